@@ -10,6 +10,7 @@ import {
   validate_header,
   validate_header_with_config_auto,
 } from "../../lib/rs_lib.internal.js";
+import * as bindings from "../../lib/rs_lib.internal.js";
 import process from "node:process";
 
 const COMMENT_MARKER = "<!-- conventional-prs-validation -->";
@@ -310,9 +311,7 @@ const loadWasmBytes = async () => {
 const initWasm = async () => {
   const bytes = await loadWasmBytes();
   const { instance } = await WebAssembly.instantiate(bytes, {
-    "./rs_lib.internal.js": {
-      __wbindgen_init_externref_table,
-    },
+    "./rs_lib.internal.js": bindings,
   });
 
   const wasmExports = instance.exports;
@@ -18,6 +18,7 @@ fi
 echo "Validating PR #$PR_NUMBER: $PR_TITLE"
 
 CONFIG_FILE=""
+CONFIG_PATH=""
 for ext in yml yaml json jsonc toml; do
   RESPONSE=$(curl -fsSL -H "Authorization: Bearer ${GITHUB_TOKEN:?}" \
     -H "Accept: application/vnd.github.v3.raw" \
@@ -25,46 +26,37 @@ for ext in yml yaml json jsonc toml; do
 
   if [ -n "$RESPONSE" ] && [ "$RESPONSE" != "404: Not Found" ]; then
     CONFIG_FILE="/tmp/semantic.$ext"
+    CONFIG_PATH=".github/semantic.$ext"
     printf '%s' "$RESPONSE" > "$CONFIG_FILE"
     echo "Found config: .github/semantic.$ext"
     break
   fi
 done
 
-ARGS=(--input "$PR_TITLE" --format github)
+if [ -n "${GITHUB_OUTPUT:-}" ]; then
+  printf 'config-path=%s\n' "$CONFIG_PATH" >> "$GITHUB_OUTPUT"
+fi
+
+ARGS=(--input "$PR_TITLE")
 if [ -n "$CONFIG_FILE" ]; then
   ARGS+=(--config "$CONFIG_FILE")
 fi
 
+# --format github writes the valid/report outputs and the job summary itself
 set +e
-OUTPUT=$(conventional-prs "${ARGS[@]}" 2>&1)
+OUTPUT=$(conventional-prs "${ARGS[@]}" --format github 2>&1)
 EXIT_CODE=$?
 set -e
 
+echo "$OUTPUT"
+
 if [ $EXIT_CODE -ne 0 ]; then
-  echo "$OUTPUT"
-  {
-    echo '```'
-    echo "$OUTPUT"
-    echo '```'
-  } >> "$GITHUB_STEP_SUMMARY"
-  
+  # The same Markdown report as the job summary
+  REPORT=$(conventional-prs "${ARGS[@]}" --format markdown 2>/dev/null || true)
+
   COMMENT_MARKER="<!-- conventional-prs-validation -->"
   COMMENT_BODY="${COMMENT_MARKER}
-## ❌ PR Title Validation Failed
-
-\`\`\`
-${OUTPUT}
-\`\`\`
-
----
-<details>
-<summary>ℹ️ How to fix</summary>
-
-Your PR title must follow the [Conventional Commits](https://www.conventionalcommits.org/) specification.
-
-Check your repository's \`.github/semantic.yml\` for allowed types and scopes.
-</details>"
+${REPORT}"
 
   EXISTING_COMMENT=$(curl -s -H "Authorization: Bearer $GITHUB_TOKEN" \
     "https://api.github.com/repos/$GITHUB_REPOSITORY/issues/$PR_NUMBER/comments" | \
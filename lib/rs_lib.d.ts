@@ -2,6 +2,14 @@
 // deno-lint-ignore-file
 // deno-fmt-ignore-file
 
+export function markdown_report_header(input: string): string
+
+export function markdown_report_header_with_config_auto(
+  input: string,
+  config_raw: string,
+  format_hint?: string | null,
+): string
+
 export function parse_semantic_yaml_config(semantic_yaml_raw: string): string
 
 export function pretty_print_header(input: string): string
@@ -8,6 +8,19 @@ export function __wbg_set_wasm(val) {
   wasm = val
 }
 
+let cachedDataViewMemory0 = null
+function getDataViewMemory0() {
+  if (
+    cachedDataViewMemory0 === null ||
+    cachedDataViewMemory0.buffer.detached === true ||
+    (cachedDataViewMemory0.buffer.detached === undefined &&
+      cachedDataViewMemory0.buffer !== wasm.memory.buffer)
+  ) {
+    cachedDataViewMemory0 = new DataView(wasm.memory.buffer)
+  }
+  return cachedDataViewMemory0
+}
+
 function getStringFromWasm0(ptr, len) {
   ptr = ptr >>> 0
   return decodeText(ptr, len)
@@ -101,6 +114,79 @@ if (!("encodeInto" in cachedTextEncoder)) {
 
 let WASM_VECTOR_LEN = 0
 
+/**
+ * @param {string} input
+ * @returns {string}
+ */
+export function markdown_report_header(input) {
+  let deferred2_0
+  let deferred2_1
+  try {
+    const ptr0 = passStringToWasm0(
+      input,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    )
+    const len0 = WASM_VECTOR_LEN
+    const ret = wasm.markdown_report_header(ptr0, len0)
+    deferred2_0 = ret[0]
+    deferred2_1 = ret[1]
+    return getStringFromWasm0(ret[0], ret[1])
+  } finally {
+    wasm.__wbindgen_free(deferred2_0, deferred2_1, 1)
+  }
+}
+
+/**
+ * @param {string} input
+ * @param {string} config_raw
+ * @param {string | null} [format_hint]
+ * @returns {string}
+ */
+export function markdown_report_header_with_config_auto(
+  input,
+  config_raw,
+  format_hint,
+) {
+  let deferred4_0
+  let deferred4_1
+  try {
+    const ptr0 = passStringToWasm0(
+      input,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    )
+    const len0 = WASM_VECTOR_LEN
+    const ptr1 = passStringToWasm0(
+      config_raw,
+      wasm.__wbindgen_malloc,
+      wasm.__wbindgen_realloc,
+    )
+    const len1 = WASM_VECTOR_LEN
+    var ptr2 = isLikeNone(format_hint)
+      ? 0
+      : passStringToWasm0(
+        format_hint,
+        wasm.__wbindgen_malloc,
+        wasm.__wbindgen_realloc,
+      )
+    var len2 = WASM_VECTOR_LEN
+    const ret = wasm.markdown_report_header_with_config_auto(
+      ptr0,
+      len0,
+      ptr1,
+      len1,
+      ptr2,
+      len2,
+    )
+    deferred4_0 = ret[0]
+    deferred4_1 = ret[1]
+    return getStringFromWasm0(ret[0], ret[1])
+  } finally {
+    wasm.__wbindgen_free(deferred4_0, deferred4_1, 1)
+  }
+}
+
 /**
  * @param {string} semantic_yaml_raw
  * @returns {string}
@@ -330,6 +416,23 @@ export function validate_header_with_config_auto(
   }
 }
 
+export function __wbg_new_e5adbf977dd56f64() {
+  const ret = new Date()
+  return ret
+}
+
+export function __wbg_toISOString_879470ce66aedff4(arg0, arg1) {
+  const ret = arg1.toISOString()
+  const ptr1 = passStringToWasm0(
+    ret,
+    wasm.__wbindgen_malloc,
+    wasm.__wbindgen_realloc,
+  )
+  const len1 = WASM_VECTOR_LEN
+  getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true)
+  getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true)
+}
+
 export function __wbindgen_init_externref_table() {
   const table = wasm.__wbindgen_externrefs
   const offset = table.grow(4)
@@ -4,7 +4,7 @@
 // deno-fmt-ignore-file
 // @ts-self-types="./rs_lib.d.ts"
 
-// source-hash: 7e81e705d1bbf1aad9d04061f8088bb1a866996b
+// source-hash: f095a83d180763a145815d0bdb7da9ec7bcdadb0
 import * as wasm from "./rs_lib.wasm";
 export * from "./rs_lib.internal.js";
 import { __wbg_set_wasm } from "./rs_lib.internal.js";
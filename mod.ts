@@ -18,7 +18,7 @@
  * if (!invalid.success) {
  *   const report = pr.summarize(invalid.issues)
  *   assertEquals(report, [
- *     "Error: Invalid commit message format",
+ *     "[CP0001, CP0002] Error: Invalid commit message format",
  *     "   ,-[ input:1:1 ]",
  *     "   |",
  *     " 1 | fature(web): add schema validation",
@@ -118,11 +118,65 @@ export interface ConventionalConfig {
   commitsOnly?: boolean
   titleAndCommits?: boolean
   anyCommit?: boolean
-  types?: readonly string[]
+  /** Allowed types, as plain names or with metadata */
+  types?: readonly (string | ConventionalType)[]
+  /** Allowed scopes; `*`, `?` and `{a,b}` patterns such as `pkg-*` are supported */
   scopes?: readonly string[] | null
+  /** Scope policy per type; `scopes` replaces the global list for that type */
+  typeScopes?: Readonly<
+    Record<string, {
+      scope?: "required" | "optional" | "forbidden"
+      scopes?: readonly string[] | null
+    }>
+  >
+  /** Types accepted in place of another, e.g. `{ feature: "feat" }` */
+  typeAliases?: Readonly<Record<string, string>>
+  /** Scopes accepted in place of another, e.g. `{ deps: "dependencies" }` */
+  scopeAliases?: Readonly<Record<string, string>>
+  /** Retired types and scopes: warnings until `until` (`YYYY-MM-DD`), errors after */
+  deprecated?: {
+    types?: Readonly<Record<string, ConventionalDeprecation>>
+    scopes?: Readonly<Record<string, ConventionalDeprecation>>
+  }
   allowMergeCommits?: boolean
   allowRevertCommits?: boolean
   targetUrl?: string
+  /** `spec-strict` matches types and scopes case-insensitively and turns off the unconfigured house-style checks */
+  mode?: "house-style" | "spec-strict"
+  /** Severity per error kind name (`trailing-spaces`) or code (`CP0015`) */
+  rules?: Readonly<Record<string, "error" | "warn" | "off">>
+  maxWarnings?: number
+  /** Description style rules, all off by default */
+  description?: {
+    case?: "lower-first" | "sentence" | "any"
+    noTrailingPeriod?: boolean
+    minLength?: number
+    maxLength?: number
+    imperative?: boolean
+    noRepeatedType?: boolean
+    noVague?: boolean
+  }
+  headerMaxLength?: number
+  /** Unit of length limits: display columns (default) or grapheme clusters */
+  lengthUnit?: "width" | "graphemes"
+}
+
+/** A commit type with metadata for changelogs, version bumps and prompts */
+export interface ConventionalType {
+  name: string
+  description?: string
+  emoji?: string
+  /** Changelog section title, e.g. "Features" */
+  section?: string
+  bump?: "major" | "minor" | "patch"
+}
+
+/** How to migrate away from a deprecated type or scope */
+export interface ConventionalDeprecation {
+  /** Name to use instead; `--fix` rewrites to it */
+  replacement?: string
+  /** Last day the name is accepted, as `YYYY-MM-DD` */
+  until?: string
 }
 
 export interface ConventionalCommit {
@@ -149,16 +203,28 @@ export interface ConventionalCommit {
   revert: null
 }
 
+type RawSpan = {
+  start: number
+  end: number
+}
+
 type RawValidationError = {
+  code: string
   kind: string
-  span: {
-    start: number
-    end: number
-  }
+  severity: "error" | "warning"
+  message: string
+  label: string
+  help: string | null
+  span: RawSpan
+  byteSpan: RawSpan
+  found?: string
+  expected?: string[]
+  candidates?: { value: string; confidence: number }[]
 }
 
 type RawValidationResult =
   | {
+    version: number
     ok: true
     header: {
       type: string
@@ -166,10 +232,13 @@ type RawValidationResult =
       breaking: boolean
       description: string
     }
+    warnings?: RawValidationError[]
   }
   | {
+    version: number
     ok: false
     errors: RawValidationError[]
+    warnings?: RawValidationError[]
   }
   | {
     ok: false
@@ -224,6 +293,8 @@ export interface StandardSchemaV1<Input = unknown, Output = Input> {
 export interface ParseIssue extends StandardSchemaV1Issue {
   readonly kind: "validation"
   readonly type: string
+  /** Stable error code such as `CP0001`, see `conventional-prs explain` */
+  readonly code?: string
   readonly input: unknown
   readonly expected?: unknown
   readonly received?: unknown
@@ -261,67 +332,9 @@ const segment = (key: PropertyKey): StandardSchemaV1PathSegment => {
   return { key }
 }
 
+// Kind names are stable kebab-case identifiers such as `invalid-type`
 const issueCodeFromKind = (kind: string): string => {
-  switch (true) {
-    case kind.startsWith("InvalidType"):
-      return "invalid_type"
-    case kind.startsWith("InvalidScope"):
-      return "invalid_scope"
-    case kind.startsWith("TypeUsedAsScope"):
-      return "type_used_as_scope"
-    case kind.startsWith("MissingClosingParen"):
-      return "missing_closing_paren"
-    case kind.startsWith("MissingSeparator"):
-      return "missing_separator"
-    case kind.startsWith("MissingDescription"):
-      return "missing_description"
-    case kind.startsWith("EmptyType"):
-      return "empty_type"
-    case kind.startsWith("EmptyScope"):
-      return "empty_scope"
-    case kind.startsWith("MissingColon"):
-      return "missing_colon"
-    case kind.startsWith("MissingSpace"):
-      return "missing_space"
-    case kind.startsWith("ExtraSpaceBeforeColon"):
-      return "extra_space_before_colon"
-    case kind.startsWith("ExtraSpaceAfterColon"):
-      return "extra_space_after_colon"
-    case kind.startsWith("TrailingSpaces"):
-      return "trailing_spaces"
-    case kind.startsWith("UnexpectedChar"):
-      return "unexpected_char"
-    default:
-      return "parse_error"
-  }
-}
-
-const parseQuotedValues = (fragment: string): readonly string[] => {
-  const matches = fragment.match(/"([^"]*)"/g)
-  if (matches === null) {
-    return []
-  }
-  return matches.map((match) => match.slice(1, -1))
-}
-
-const parseFoundValue = (kind: string): string | undefined => {
-  const match = /found: "([^"]+)"/.exec(kind)
-  if (match === null) {
-    return undefined
-  }
-  return match[1]
-}
-
-const parseExpectedValues = (
-  kind: string,
-  label: string,
-): readonly string[] => {
-  const pattern = new RegExp(`${label}: \\[(.*)\\]`)
-  const match = pattern.exec(kind)
-  if (match === null) {
-    return []
-  }
-  return parseQuotedValues(match[1])
+  return kind.replaceAll("-", "_")
 }
 
 type IssueDetails = {
@@ -330,11 +343,14 @@ type IssueDetails = {
   received?: unknown
 }
 
-const issueDetailsFromKind = (kind: string, code: string): IssueDetails => {
+const issueDetailsFromKind = (
+  entry: RawValidationError,
+  code: string,
+): IssueDetails => {
+  const found = entry.found
+  const expected = entry.expected ?? []
   switch (code) {
     case "invalid_type": {
-      const found = parseFoundValue(kind)
-      const expected = parseExpectedValues(kind, "expected")
       if (found !== undefined && expected.length > 0) {
         return {
           message: `Invalid commit type \"${found}\". Expected one of: ${
@@ -347,8 +363,6 @@ const issueDetailsFromKind = (kind: string, code: string): IssueDetails => {
       return { message: "Invalid commit type." }
     }
     case "invalid_scope": {
-      const found = parseFoundValue(kind)
-      const expected = parseExpectedValues(kind, "expected")
       if (found !== undefined && expected.length > 0) {
         return {
           message: `Invalid scope \"${found}\". Expected one of: ${
@@ -361,14 +375,12 @@ const issueDetailsFromKind = (kind: string, code: string): IssueDetails => {
       return { message: "Invalid scope." }
     }
     case "type_used_as_scope": {
-      const found = parseFoundValue(kind)
-      const expectedScopes = parseExpectedValues(kind, "expected_scopes")
-      if (found !== undefined && expectedScopes.length > 0) {
+      if (found !== undefined && expected.length > 0) {
         return {
           message: `Scope \"${found}\" is a commit type. Expected scopes: ${
-            expectedScopes.join(", ")
+            expected.join(", ")
           }.`,
-          expected: expectedScopes,
+          expected,
           received: found,
         }
       }
@@ -399,7 +411,7 @@ const issueDetailsFromKind = (kind: string, code: string): IssueDetails => {
     case "unexpected_char":
       return { message: "Unexpected character in commit header." }
     default:
-      return { message: kind }
+      return { message: entry.message }
   }
 }
 
@@ -413,13 +425,28 @@ const pathForCode = (
       return [segment("config")]
     case "invalid_type":
     case "empty_type":
+    case "type_alias":
+    case "deprecated_type":
+    case "type_case":
       return [segment("type")]
     case "invalid_scope":
     case "type_used_as_scope":
     case "empty_scope":
+    case "scope_required":
+    case "scope_forbidden":
+    case "scope_not_allowed_for_type":
+    case "scope_alias":
+    case "deprecated_scope":
       return [segment("scope")]
     case "missing_description":
     case "trailing_spaces":
+    case "description_case":
+    case "description_trailing_period":
+    case "description_too_short":
+    case "description_too_long":
+    case "description_not_imperative":
+    case "description_repeats_type":
+    case "vague_description":
       return [segment("description")]
     case "missing_separator":
     case "missing_colon":
@@ -457,13 +484,17 @@ const normalizeUnknownConfig = (config: unknown): ConventionalConfig => {
   const record = config as Record<string, unknown>
 
   if (record["types"] !== undefined && !Array.isArray(record["types"])) {
-    throw new TypeError("config.types must be an array of strings")
+    throw new TypeError("config.types must be an array of strings or types")
   }
   if (
     Array.isArray(record["types"]) &&
-    record["types"].some((entry) => typeof entry !== "string")
+    record["types"].some((entry) =>
+      typeof entry !== "string" &&
+      (typeof entry !== "object" || entry === null ||
+        typeof (entry as Record<string, unknown>)["name"] !== "string")
+    )
   ) {
-    throw new TypeError("config.types must be an array of strings")
+    throw new TypeError("config.types must be an array of strings or types")
   }
 
   if (
@@ -521,7 +552,9 @@ const yamlScalar = (value: string | boolean | null): string => {
   return JSON.stringify(value)
 }
 
-const yamlArray = (values: readonly string[]): string => {
+const yamlArray = (
+  values: readonly (string | ConventionalType)[],
+): string => {
   return `[${values.map((value) => JSON.stringify(value)).join(", ")}]`
 }
 
@@ -553,6 +586,18 @@ const configToYaml = (config: ConventionalConfig): string => {
       lines.push(`scopes: ${yamlArray(config.scopes)}`)
     }
   }
+  if (config.typeScopes !== undefined) {
+    lines.push(`typeScopes: ${JSON.stringify(config.typeScopes)}`)
+  }
+  if (config.typeAliases !== undefined) {
+    lines.push(`typeAliases: ${JSON.stringify(config.typeAliases)}`)
+  }
+  if (config.scopeAliases !== undefined) {
+    lines.push(`scopeAliases: ${JSON.stringify(config.scopeAliases)}`)
+  }
+  if (config.deprecated !== undefined) {
+    lines.push(`deprecated: ${JSON.stringify(config.deprecated)}`)
+  }
   if (config.allowMergeCommits !== undefined) {
     lines.push(`allowMergeCommits: ${yamlScalar(config.allowMergeCommits)}`)
   }
@@ -562,6 +607,24 @@ const configToYaml = (config: ConventionalConfig): string => {
   if (config.targetUrl !== undefined) {
     lines.push(`targetUrl: ${yamlScalar(config.targetUrl)}`)
   }
+  if (config.mode !== undefined) {
+    lines.push(`mode: ${yamlScalar(config.mode)}`)
+  }
+  if (config.rules !== undefined) {
+    lines.push(`rules: ${JSON.stringify(config.rules)}`)
+  }
+  if (config.maxWarnings !== undefined) {
+    lines.push(`maxWarnings: ${config.maxWarnings}`)
+  }
+  if (config.description !== undefined) {
+    lines.push(`description: ${JSON.stringify(config.description)}`)
+  }
+  if (config.headerMaxLength !== undefined) {
+    lines.push(`headerMaxLength: ${config.headerMaxLength}`)
+  }
+  if (config.lengthUnit !== undefined) {
+    lines.push(`lengthUnit: ${config.lengthUnit}`)
+  }
 
   return `${lines.join("\n")}\n`
 }
@@ -572,7 +635,7 @@ const parseRawValidationResult = (raw: string): RawValidationResult => {
 
 const toStandardIssue = (entry: RawValidationError): StandardSchemaV1Issue => {
   const code = issueCodeFromKind(entry.kind)
-  const details = issueDetailsFromKind(entry.kind, code)
+  const details = issueDetailsFromKind(entry, code)
   return {
     message: details.message,
     path: pathForCode(code),
@@ -585,10 +648,11 @@ const toParseIssue = (
   config: ConventionalConfig | undefined,
 ): ParseIssue => {
   const type = issueCodeFromKind(entry.kind)
-  const details = issueDetailsFromKind(entry.kind, type)
+  const details = issueDetailsFromKind(entry, type)
   return {
     kind: "validation",
     type,
+    code: entry.code,
     input,
     expected: details.expected,
     received: details.received,
@@ -1,4 +1,4 @@
-use conventional_prs::{Config, ConfigFormat, ConventionalParser, OutputFormat};
+use conventional_prs::{Config, ConfigError, ConfigFormat, ErrorReporter, Linter, OutputFormat};
 use serde_json::json;
 use std::str::FromStr;
 use wasm_bindgen::prelude::*;
@@ -10,66 +10,67 @@ fn config_json(config: &Config) -> serde_json::Value {
         "commitsOnly": config.commits_only,
         "titleAndCommits": config.title_and_commits,
         "anyCommit": config.any_commit,
-        "types": config.types,
+        "types": config.type_configs(),
         "scopes": config.scopes,
+        "typeScopes": config.type_scopes,
+        "typeAliases": config.type_aliases,
+        "scopeAliases": config.scope_aliases,
+        "deprecated": config.deprecated,
         "allowMergeCommits": config.allow_merge_commits,
         "allowRevertCommits": config.allow_revert_commits,
         "targetUrl": config.target_url,
+        "mode": config.mode,
+        "rules": config.rules,
+        "maxWarnings": config.max_warnings,
+        "description": config.description,
+        "headerMaxLength": config.header_max_length,
+        "lengthUnit": config.length_unit,
     })
 }
 
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+extern "C" {
+    type Date;
+
+    #[wasm_bindgen(constructor)]
+    fn new() -> Date;
+
+    #[wasm_bindgen(method, js_name = toISOString)]
+    fn to_iso_string(this: &Date) -> String;
+}
+
+/// The system clock is unavailable in wasm, so deprecations expire by the JS date
+#[cfg(target_arch = "wasm32")]
+fn linter(config: &Config) -> Linter {
+    Linter::from_config(config).with_today(&Date::new().to_iso_string()[..10])
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn linter(config: &Config) -> Linter {
+    Linter::from_config(config)
+}
+
 fn validate_with_config(input: &str, config: &Config) -> String {
-    let parser = ConventionalParser::new(config.types.clone(), config.scopes.clone());
-    let result = parser.parse(input);
-
-    if result.is_ok() {
-        let header = result
-            .output()
-            .expect("parse output must exist when parse result is ok");
-        json!({
-            "ok": true,
-            "header": {
-                "type": header.commit_type,
-                "scope": header.scope,
-                "breaking": header.breaking,
-                "description": header.description
-            }
-        })
-        .to_string()
-    } else {
-        let errors = result
-            .errors()
-            .expect("parse errors must exist when parse result is err")
-            .iter()
-            .map(|error| {
-                json!({
-                    "kind": format!("{:?}", error.kind),
-                    "span": {
-                        "start": error.span.start,
-                        "end": error.span.end
-                    }
-                })
-            })
-            .collect::<Vec<_>>();
-
-        json!({
-            "ok": false,
-            "errors": errors
-        })
-        .to_string()
-    }
+    linter(config).lint(input).to_json_report().to_json()
 }
 
 fn pretty_print_with_config(input: &str, config: &Config) -> String {
-    let parser = ConventionalParser::new(config.types.clone(), config.scopes.clone());
-    let result = parser.parse(input);
+    linter(config)
+        .lint(input)
+        .report(OutputFormat::Ascii, config.charset)
+        .unwrap_or_default()
+}
+
+fn markdown_report_with_config(input: &str, config: &Config) -> String {
+    let diagnostics = linter(config).lint(input).diagnostics();
 
-    if result.is_ok() {
+    if diagnostics.is_empty() {
         String::new()
     } else {
-        result
-            .report(OutputFormat::Ascii, config.charset)
-            .unwrap_or_default()
+        ErrorReporter::new(OutputFormat::Markdown, config.charset)
+            .with_target_url(&config.target_url)
+            .report_errors_named("title", input, &diagnostics)
     }
 }
 
@@ -87,8 +88,11 @@ fn parse_config(config_raw: &str, format_hint: Option<String>) -> Result<Config,
                 ConfigFormat::Yaml,
                 ConfigFormat::Toml,
             ] {
-                if let Ok(config) = Config::parse_str(config_raw, format) {
-                    return Ok(config);
+                match Config::parse_str(config_raw, format) {
+                    Ok(config) => return Ok(config),
+                    // The config parsed in this format, so the others won't fix it
+                    Err(error @ ConfigError::UnknownRule { .. }) => return Err(format!("{error}")),
+                    Err(_) => {}
                 }
             }
 
@@ -105,7 +109,7 @@ pub fn validate_header(input: &str) -> String {
 
 #[wasm_bindgen]
 pub fn validate_header_with_config(input: &str, semantic_yaml_raw: &str) -> String {
-    match serde_yaml::from_str::<Config>(semantic_yaml_raw) {
+    match Config::parse_str(semantic_yaml_raw, ConfigFormat::Yaml) {
         Ok(config) => validate_with_config(input, &config),
         Err(error) => json!({
             "ok": false,
@@ -123,7 +127,7 @@ pub fn pretty_print_header(input: &str) -> String {
 
 #[wasm_bindgen]
 pub fn pretty_print_header_with_config(input: &str, semantic_yaml_raw: &str) -> String {
-    match serde_yaml::from_str::<Config>(semantic_yaml_raw) {
+    match Config::parse_str(semantic_yaml_raw, ConfigFormat::Yaml) {
         Ok(config) => pretty_print_with_config(input, &config),
         Err(error) => format!("Config parse error: {error}"),
     }
@@ -157,9 +161,27 @@ pub fn pretty_print_header_with_config_auto(
     }
 }
 
+#[wasm_bindgen]
+pub fn markdown_report_header(input: &str) -> String {
+    let config = Config::default();
+    markdown_report_with_config(input, &config)
+}
+
+#[wasm_bindgen]
+pub fn markdown_report_header_with_config_auto(
+    input: &str,
+    config_raw: &str,
+    format_hint: Option<String>,
+) -> String {
+    match parse_config(config_raw, format_hint) {
+        Ok(config) => markdown_report_with_config(input, &config),
+        Err(error) => format!("Config parse error: {error}"),
+    }
+}
+
 #[wasm_bindgen]
 pub fn parse_semantic_yaml_config(semantic_yaml_raw: &str) -> String {
-    match serde_yaml::from_str::<Config>(semantic_yaml_raw) {
+    match Config::parse_str(semantic_yaml_raw, ConfigFormat::Yaml) {
         Ok(config) => json!({
             "ok": true,
             "config": config_json(&config)
@@ -198,10 +220,7 @@ mod tests {
                 .as_array()
                 .expect("errors should be array")
                 .iter()
-                .any(|entry| entry["kind"]
-                    .as_str()
-                    .unwrap_or_default()
-                    .contains("InvalidType"))
+                .any(|entry| entry["kind"] == "invalid-type")
         );
     }
 
@@ -239,6 +258,24 @@ mod tests {
         assert!(output.contains("Invalid commit type"));
     }
 
+    #[test]
+    fn markdown_report_is_empty_for_valid_header() {
+        assert_eq!(markdown_report_header("feat(api): add endpoint"), "");
+    }
+
+    #[test]
+    fn markdown_report_links_target_url() {
+        let output = markdown_report_header_with_config_auto(
+            "feta: typo in type",
+            "types: [feat]\ntargetUrl: https://example.com\n",
+            Some("yaml".to_string()),
+        );
+
+        assert!(output.starts_with("## Invalid conventional commit in `title`"));
+        assert!(output.contains("**Suggested title:** `feat: typo in type`"));
+        assert!(output.contains("(https://example.com)"));
+    }
+
     #[test]
     fn pretty_report_contains_config_error_for_invalid_yaml() {
         let output = pretty_print_header_with_config("feat: add endpoint", "types: [feat");
@@ -311,4 +348,20 @@ scopes = ["core"]
 
         assert!(output.contains("Config parse error"));
     }
+
+    #[test]
+    fn auto_config_reports_unknown_rules() {
+        let output = validate_header_with_config_auto(
+            "feat: add endpoint",
+            r#"{"rules": {"trailing-space": "warn"}}"#,
+            None,
+        );
+        let json: serde_json::Value = serde_json::from_str(&output).expect("valid json output");
+
+        assert_eq!(json["ok"], false);
+        assert_eq!(
+            json["configError"],
+            "Unknown rule 'trailing-space' in `rules`. Did you mean 'trailing-spaces'?"
+        );
+    }
 }
@@ -9,13 +9,6 @@ if [[ -z "${commit_msg_file}" || ! -f "${commit_msg_file}" ]]; then
   exit 1
 fi
 
-commit_title=$(head -n 1 "${commit_msg_file}" | tr -d '\r')
-
-if [[ -z "${commit_title}" ]]; then
-  echo "ERROR: commit title must not be empty." >&2
-  exit 1
-fi
-
 if command -v conventional-prs >/dev/null 2>&1; then
   validator=(conventional-prs)
 elif [[ -x "./target/debug/conventional-prs" ]]; then
@@ -26,9 +19,9 @@ else
   validator=(cargo run --quiet --)
 fi
 
-if ! "${validator[@]}" --input "${commit_title}" >/dev/null 2>&1; then
-  echo "ERROR: commit title does not follow Conventional Commits." >&2
+if ! "${validator[@]}" --commit-msg-file "${commit_msg_file}" >/dev/null 2>&1; then
+  echo "ERROR: commit message does not follow Conventional Commits." >&2
   echo >&2
-  "${validator[@]}" --input "${commit_title}"
+  "${validator[@]}" --commit-msg-file "${commit_msg_file}"
   exit 1
 fi
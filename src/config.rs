@@ -1,5 +1,8 @@
+use crate::explain::EXPLANATIONS;
+use crate::similar;
 use ariadne::CharSet;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
@@ -17,6 +20,14 @@ pub enum ConfigError {
     TomlError(#[from] toml::de::Error),
     #[error("Unsupported config format: {0}")]
     UnsupportedFormat(String),
+    #[error(
+        "Unknown rule '{rule}' in `rules`{}",
+        .suggestion.as_ref().map(|s| format!(". Did you mean '{s}'?")).unwrap_or_default()
+    )]
+    UnknownRule {
+        rule: String,
+        suggestion: Option<String>,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -71,12 +82,35 @@ pub struct Config {
     #[serde(default)]
     pub any_commit: bool,
 
-    #[serde(default = "default_types")]
+    /// Allowed types. In a config file each entry is a plain name or an object with
+    /// metadata (see [`TypeConfig`]); the metadata goes to `type_metadata`.
+    #[serde(default = "default_types", deserialize_with = "deserialize_type_names")]
     pub types: Vec<String>,
 
+    /// Metadata of the types written as objects in `types`. Filled in by
+    /// [`Config::parse_str`] and [`Config::load`]; see [`Config::type_configs`].
+    #[serde(skip)]
+    pub type_metadata: Vec<TypeConfig>,
+
     #[serde(default)]
     pub scopes: Option<Vec<String>>,
 
+    /// Scope policy per type, e.g. `feat` must have a scope and `ci` must not
+    #[serde(default)]
+    pub type_scopes: BTreeMap<String, TypeScopes>,
+
+    /// Types accepted in place of another and replaced by it, e.g. `feature: feat`
+    #[serde(default)]
+    pub type_aliases: BTreeMap<String, String>,
+
+    /// Scopes accepted in place of another and replaced by it, e.g. `deps: dependencies`
+    #[serde(default)]
+    pub scope_aliases: BTreeMap<String, String>,
+
+    /// Retired types and scopes, still accepted with a warning
+    #[serde(default)]
+    pub deprecated: DeprecatedConfig,
+
     #[serde(default)]
     pub allow_merge_commits: bool,
 
@@ -86,6 +120,32 @@ pub struct Config {
     #[serde(default = "default_target_url")]
     pub target_url: String,
 
+    /// Whether house-style rules beyond the Conventional Commits spec apply by default
+    #[serde(default)]
+    pub mode: ParserMode,
+
+    /// Severity per error kind, keyed by name (`trailing-spaces`) or code (`CP0015`).
+    /// Kinds that are not listed are errors, except the house-style checks in spec-strict
+    /// mode (see `ParseErrorKind::is_house_style`).
+    #[serde(default)]
+    pub rules: BTreeMap<String, Severity>,
+
+    /// Fail when there are more warnings than this, across all validated messages
+    #[serde(default)]
+    pub max_warnings: Option<usize>,
+
+    /// Style rules for the description; all of them are off by default
+    #[serde(default)]
+    pub description: DescriptionConfig,
+
+    /// Maximum header length, not counting `fixup!`/`squash!` prefixes
+    #[serde(default)]
+    pub header_max_length: Option<usize>,
+
+    /// How length limits are measured
+    #[serde(default)]
+    pub length_unit: LengthUnit,
+
     #[serde(default = "default_charset", skip_serializing)]
     pub charset: CharSetConfig,
 }
@@ -97,6 +157,322 @@ pub enum CharSetConfig {
     Unicode,
 }
 
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DescriptionConfig {
+    #[serde(default)]
+    pub case: DescriptionCase,
+
+    /// Reject descriptions that end with a period
+    #[serde(default)]
+    pub no_trailing_period: bool,
+
+    #[serde(default)]
+    pub min_length: Option<usize>,
+
+    #[serde(default)]
+    pub max_length: Option<usize>,
+
+    /// Require the imperative mood: `add`, not `added`, `adds` or `adding`
+    #[serde(default)]
+    pub imperative: bool,
+
+    /// Reject descriptions that start with the type, like `fix: fix crash`
+    #[serde(default)]
+    pub no_repeated_type: bool,
+
+    /// Reject descriptions made of vague words only, like `update` or `minor fixes`
+    #[serde(default)]
+    pub no_vague: bool,
+}
+
+/// A commit type and its metadata, for changelogs, version bumps and prompts.
+/// Written as a plain name (`feat`) or an object (`{ name: feat, bump: minor }`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(into = "TypeEntry")]
+pub struct TypeConfig {
+    pub name: String,
+    /// What the type is for, e.g. "A new feature"
+    pub description: Option<String>,
+    pub emoji: Option<String>,
+    /// Changelog section title, e.g. "Features"
+    pub section: Option<String>,
+    /// Version bump for a release containing the type; `None` means no release
+    pub bump: Option<Bump>,
+}
+
+impl TypeConfig {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Self::default()
+        }
+    }
+}
+
+impl From<&str> for TypeConfig {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<String> for TypeConfig {
+    fn from(name: String) -> Self {
+        Self::new(name)
+    }
+}
+
+/// Object form of a type. Unknown keys are rejected so typos like `bumb` are reported.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TypeFields {
+    name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    emoji: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    section: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    bump: Option<Bump>,
+}
+
+impl From<TypeFields> for TypeConfig {
+    fn from(fields: TypeFields) -> Self {
+        Self {
+            name: fields.name,
+            description: fields.description,
+            emoji: fields.emoji,
+            section: fields.section,
+            bump: fields.bump,
+        }
+    }
+}
+
+/// Serialized form of `TypeConfig`: types without metadata stay plain strings
+#[derive(Serialize)]
+#[serde(untagged)]
+enum TypeEntry {
+    Name(String),
+    Full(TypeFields),
+}
+
+impl From<TypeConfig> for TypeEntry {
+    fn from(config: TypeConfig) -> Self {
+        if config == TypeConfig::new(config.name.clone()) {
+            return TypeEntry::Name(config.name);
+        }
+        TypeEntry::Full(TypeFields {
+            name: config.name,
+            description: config.description,
+            emoji: config.emoji,
+            section: config.section,
+            bump: config.bump,
+        })
+    }
+}
+
+// Not `#[serde(untagged)]`: its error hides which key or value of an object is wrong
+impl<'de> Deserialize<'de> for TypeConfig {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct TypeVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for TypeVisitor {
+            type Value = TypeConfig;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a type name or an object with a `name`")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, name: &str) -> Result<TypeConfig, E> {
+                Ok(TypeConfig::new(name))
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                map: A,
+            ) -> Result<TypeConfig, A::Error> {
+                TypeFields::deserialize(serde::de::value::MapAccessDeserializer::new(map))
+                    .map(TypeConfig::from)
+            }
+        }
+
+        deserializer.deserialize_any(TypeVisitor)
+    }
+}
+
+/// Semantic version impact of a type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Bump {
+    Patch,
+    Minor,
+    Major,
+}
+
+/// Retired types and scopes, keyed by name
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeprecatedConfig {
+    #[serde(default)]
+    pub types: BTreeMap<String, Deprecation>,
+
+    #[serde(default)]
+    pub scopes: BTreeMap<String, Deprecation>,
+}
+
+/// How to migrate away from a deprecated type or scope
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Deprecation {
+    /// The name to use instead
+    #[serde(default)]
+    pub replacement: Option<String>,
+
+    /// Last day (`YYYY-MM-DD`, UTC) the name is accepted; it is an error afterwards
+    #[serde(default, deserialize_with = "deserialize_date")]
+    pub until: Option<String>,
+}
+
+impl Deprecation {
+    /// Whether `today` (`YYYY-MM-DD`) is past the `until` date
+    pub fn is_expired(&self, today: &str) -> bool {
+        // ISO dates compare like strings
+        self.until.as_deref().is_some_and(|until| today > until)
+    }
+}
+
+/// A date written as a string, or as a TOML local date (`until = 2026-12-31`)
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DateValue {
+    Text(String),
+    Toml(toml::value::Datetime),
+}
+
+fn deserialize_date<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error> {
+    let date = match Option::<DateValue>::deserialize(deserializer)? {
+        None => return Ok(None),
+        Some(DateValue::Text(date)) => date,
+        Some(DateValue::Toml(date)) => date.to_string(),
+    };
+    let is_date = date.len() == 10
+        && date.char_indices().all(|(idx, c)| match idx {
+            4 | 7 => c == '-',
+            _ => c.is_ascii_digit(),
+        });
+    if !is_date {
+        return Err(serde::de::Error::custom(format!(
+            "invalid date '{date}', expected YYYY-MM-DD"
+        )));
+    }
+    Ok(Some(date))
+}
+
+/// Today's date in UTC as `YYYY-MM-DD`. `wasm32-unknown-unknown` has no system clock,
+/// so there it is empty and nothing expires unless a date is passed to `with_today`.
+pub(crate) fn today() -> String {
+    if cfg!(all(target_arch = "wasm32", target_os = "unknown")) {
+        return String::new();
+    }
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    // Civil date from days since the epoch (Howard Hinnant's algorithm)
+    let days = (secs / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Scope settings for one type
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeScopes {
+    #[serde(default)]
+    pub scope: ScopePolicy,
+
+    /// Scopes allowed with this type, replacing `Config::scopes` for it
+    #[serde(default)]
+    pub scopes: Option<Vec<String>>,
+}
+
+/// Whether a type takes a scope
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScopePolicy {
+    Required,
+    #[default]
+    Optional,
+    Forbidden,
+}
+
+/// Required case of the first letter of the description
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DescriptionCase {
+    /// `add login`; descriptions starting with an acronym such as `API` are accepted
+    LowerFirst,
+    /// `Add login`
+    Sentence,
+    #[default]
+    Any,
+}
+
+/// Which rules apply when `rules` does not say otherwise
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ParserMode {
+    /// Every rule, including house style such as lowercase types
+    #[default]
+    HouseStyle,
+    /// Only what the spec requires: types and scopes match case-insensitively, and the
+    /// house-style checks that run unconfigured (type case, whitespace padding) are off
+    SpecStrict,
+}
+
+/// Unit of length limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LengthUnit {
+    /// Terminal display columns: CJK characters and emoji count as 2
+    #[default]
+    Width,
+    /// User-perceived characters (extended grapheme clusters)
+    Graphemes,
+}
+
+impl LengthUnit {
+    /// Plural noun for messages, e.g. "72 columns"
+    pub fn noun(self) -> &'static str {
+        match self {
+            LengthUnit::Width => "columns",
+            LengthUnit::Graphemes => "characters",
+        }
+    }
+}
+
+/// How a rule is enforced
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Reported, and the message is invalid
+    #[default]
+    Error,
+    /// Reported, but the message is still valid
+    #[serde(alias = "warning")]
+    Warn,
+    /// Not reported
+    Off,
+}
+
 impl From<CharSetConfig> for CharSet {
     fn from(config: CharSetConfig) -> Self {
         match config {
@@ -126,6 +502,36 @@ fn default_types() -> Vec<String> {
     ]
 }
 
+/// Names of `types` entries, checking the metadata of those written as objects
+fn deserialize_type_names<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<String>, D::Error> {
+    let types = Vec::<TypeConfig>::deserialize(deserializer)?;
+    Ok(types.into_iter().map(|t| t.name).collect())
+}
+
+/// The `types` entries of a config file, with their metadata
+#[derive(Deserialize)]
+struct TypeEntries {
+    #[serde(default)]
+    types: Vec<TypeConfig>,
+}
+
+fn from_format<T: serde::de::DeserializeOwned>(
+    content: &str,
+    format: ConfigFormat,
+) -> Result<T, ConfigError> {
+    Ok(match format {
+        ConfigFormat::Yaml => serde_yaml::from_str(content)?,
+        ConfigFormat::Json => serde_json::from_str(content)?,
+        ConfigFormat::Jsonc => {
+            let stripped = json_comments::StripComments::new(content.as_bytes());
+            serde_json::from_reader(stripped)?
+        }
+        ConfigFormat::Toml => toml::from_str(content)?,
+    })
+}
+
 fn default_target_url() -> String {
     "https://github.com/Ezard/semantic-prs".to_string()
 }
@@ -143,10 +549,21 @@ impl Default for Config {
             title_and_commits: false,
             any_commit: false,
             types: default_types(),
+            type_metadata: Vec::new(),
             scopes: None,
+            type_scopes: BTreeMap::new(),
+            type_aliases: BTreeMap::new(),
+            scope_aliases: BTreeMap::new(),
+            deprecated: DeprecatedConfig::default(),
             allow_merge_commits: false,
             allow_revert_commits: false,
             target_url: default_target_url(),
+            mode: ParserMode::default(),
+            rules: BTreeMap::new(),
+            max_warnings: None,
+            description: DescriptionConfig::default(),
+            header_max_length: None,
+            length_unit: LengthUnit::default(),
             charset: default_charset(),
         }
     }
@@ -212,36 +629,77 @@ impl Config {
             return Self::parse_str(&content, format);
         }
 
-        if let Ok(config) = Self::parse_str(&content, ConfigFormat::Json) {
-            return Ok(config);
-        }
-        if let Ok(config) = Self::parse_str(&content, ConfigFormat::Jsonc) {
-            return Ok(config);
-        }
-        if let Ok(config) = Self::parse_str(&content, ConfigFormat::Yaml) {
-            return Ok(config);
-        }
-        if let Ok(config) = Self::parse_str(&content, ConfigFormat::Toml) {
-            return Ok(config);
+        for format in [
+            ConfigFormat::Json,
+            ConfigFormat::Jsonc,
+            ConfigFormat::Yaml,
+            ConfigFormat::Toml,
+        ] {
+            match Self::parse_str(&content, format) {
+                Ok(config) => return Ok(config),
+                // The file parsed in this format, so the others won't fix it
+                Err(error @ ConfigError::UnknownRule { .. }) => return Err(error),
+                Err(_) => {}
+            }
         }
 
         Ok(Self::default())
     }
 
+    /// Parse a config, including the metadata of its types, and check that every key of
+    /// `rules` names a built-in error kind
     pub fn parse_str(content: &str, format: ConfigFormat) -> Result<Self, ConfigError> {
-        match format {
-            ConfigFormat::Yaml => Ok(serde_yaml::from_str(content)?),
-            ConfigFormat::Json => Ok(serde_json::from_str(content)?),
-            ConfigFormat::Jsonc => {
-                let stripped = json_comments::StripComments::new(content.as_bytes());
-                Ok(serde_json::from_reader(stripped)?)
-            }
-            ConfigFormat::Toml => Ok(toml::from_str(content)?),
+        let mut config: Self = from_format(content, format)?;
+        config.validate_rules()?;
+        let TypeEntries { types } = from_format(content, format)?;
+        config.type_metadata = types
+            .into_iter()
+            .filter(|t| *t != TypeConfig::new(t.name.clone()))
+            .collect();
+        Ok(config)
+    }
+
+    /// Reject `rules` keys that are neither the name nor the code of a built-in error
+    /// kind, suggesting the closest one. Severities of custom rules are set with
+    /// `Linter::with_rule_severity` instead.
+    pub fn validate_rules(&self) -> Result<(), ConfigError> {
+        let known: Vec<String> = EXPLANATIONS
+            .iter()
+            .flat_map(|explanation| [explanation.name, explanation.code])
+            .map(String::from)
+            .collect();
+        match self.rules.keys().find(|rule| !known.contains(rule)) {
+            None => Ok(()),
+            Some(rule) => Err(ConfigError::UnknownRule {
+                rule: rule.clone(),
+                suggestion: similar::rank(rule, &known, 1)
+                    .into_iter()
+                    .next()
+                    .map(|candidate| candidate.value),
+            }),
         }
     }
+
+    /// Metadata of a type by name, if it has any
+    pub fn type_config(&self, name: &str) -> Option<&TypeConfig> {
+        self.type_metadata.iter().find(|t| t.name == name)
+    }
+
+    /// Every allowed type with its metadata, in the order of `types`
+    pub fn type_configs(&self) -> Vec<TypeConfig> {
+        self.types
+            .iter()
+            .map(|name| {
+                self.type_config(name)
+                    .cloned()
+                    .unwrap_or_else(|| TypeConfig::new(name.clone()))
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
 
@@ -307,6 +765,213 @@ targetUrl: "https://example.com"
         assert_eq!(config.types, vec!["feat", "fix"]);
     }
 
+    #[test]
+    fn test_rules_parsing() {
+        let yaml = r#"
+rules:
+  trailing-spaces: warn
+  CP0016: warning
+  extra-space-after-colon: off
+maxWarnings: 3
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.rules["trailing-spaces"], Severity::Warn);
+        assert_eq!(config.rules["CP0016"], Severity::Warn);
+        assert_eq!(config.rules["extra-space-after-colon"], Severity::Off);
+        assert_eq!(config.max_warnings, Some(3));
+        assert!(Config::default().rules.is_empty());
+    }
+
+    #[test]
+    fn test_unknown_rules_are_rejected() {
+        let error =
+            Config::parse_str("rules:\n  trailing-space: warn\n", ConfigFormat::Yaml).unwrap_err();
+        assert!(matches!(
+            &error,
+            ConfigError::UnknownRule { rule, suggestion }
+                if rule == "trailing-space" && suggestion.as_deref() == Some("trailing-spaces")
+        ));
+        assert_eq!(
+            error.to_string(),
+            "Unknown rule 'trailing-space' in `rules`. Did you mean 'trailing-spaces'?"
+        );
+
+        let error = Config::parse_str(r#"{ "rules": { "xyzzy": "off" } }"#, ConfigFormat::Json)
+            .unwrap_err();
+        assert_eq!(error.to_string(), "Unknown rule 'xyzzy' in `rules`");
+
+        let config = Config::parse_str("[rules]\nCP0015 = \"warn\"\n", ConfigFormat::Toml);
+        assert!(config.is_ok());
+    }
+
+    #[test]
+    fn test_type_metadata_parsing() {
+        let yaml = r#"
+types:
+  - name: feat
+    description: A new feature
+    emoji: "✨"
+    section: Features
+    bump: minor
+  - fix
+"#;
+        let config = Config::parse_str(yaml, ConfigFormat::Yaml).unwrap();
+        assert_eq!(config.types, vec!["feat", "fix"]);
+        let feat = config.type_config("feat").unwrap();
+        assert_eq!(feat.description.as_deref(), Some("A new feature"));
+        assert_eq!(feat.emoji.as_deref(), Some("✨"));
+        assert_eq!(feat.section.as_deref(), Some("Features"));
+        assert_eq!(feat.bump, Some(Bump::Minor));
+        assert_eq!(config.type_config("fix"), None);
+
+        // Types without metadata are written back as plain names
+        let json = serde_json::to_value(config.type_configs()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                {
+                    "name": "feat",
+                    "description": "A new feature",
+                    "emoji": "✨",
+                    "section": "Features",
+                    "bump": "minor"
+                },
+                "fix"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_type_metadata_errors() {
+        let error = serde_yaml::from_str::<Config>("types:\n  - name: feat\n    bumb: minor\n")
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("unknown field `bumb`"), "{error}");
+
+        let error = serde_yaml::from_str::<Config>("types:\n  - name: feat\n    bump: huge\n")
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("unknown variant `huge`"), "{error}");
+
+        let error = serde_json::from_str::<Config>(r#"{ "types": [{ "bump": "minor" }] }"#)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("missing field `name`"), "{error}");
+
+        let error = toml::from_str::<Config>("types = [{ name = \"feat\", bump = \"huge\" }]")
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("unknown variant `huge`"), "{error}");
+        assert!(toml::from_str::<Config>("types = [\"feat\", { name = \"fix\" }]").is_ok());
+    }
+
+    #[test]
+    fn test_deprecated_parsing() {
+        let yaml = r#"
+deprecated:
+  scopes:
+    frontend:
+      replacement: web
+      until: 2026-12-31
+  types:
+    feature: {}
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        let frontend = &config.deprecated.scopes["frontend"];
+        assert_eq!(frontend.replacement.as_deref(), Some("web"));
+        assert_eq!(frontend.until.as_deref(), Some("2026-12-31"));
+        assert!(!frontend.is_expired("2026-12-31"));
+        assert!(frontend.is_expired("2027-01-01"));
+        assert_eq!(config.deprecated.types["feature"], Deprecation::default());
+        assert!(!config.deprecated.types["feature"].is_expired("9999-12-31"));
+
+        let invalid = "deprecated:\n  scopes:\n    frontend:\n      until: next year\n";
+        assert!(serde_yaml::from_str::<Config>(invalid).is_err());
+
+        let toml_str = "[deprecated.scopes.frontend]\nuntil = 2026-12-31\n";
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(
+            config.deprecated.scopes["frontend"].until.as_deref(),
+            Some("2026-12-31")
+        );
+    }
+
+    #[test]
+    fn test_today_is_an_iso_date() {
+        let today = today();
+        assert_eq!(today.len(), 10);
+        assert!(today.as_str() > "2024-01-01");
+    }
+
+    #[test]
+    fn test_alias_parsing() {
+        let yaml = r#"
+typeAliases:
+  feature: feat
+  bugfix: fix
+scopeAliases:
+  deps: dependencies
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.type_aliases["feature"], "feat");
+        assert_eq!(config.type_aliases["bugfix"], "fix");
+        assert_eq!(config.scope_aliases["deps"], "dependencies");
+        assert!(Config::default().type_aliases.is_empty());
+    }
+
+    #[test]
+    fn test_type_scopes_parsing() {
+        let yaml = r#"
+scopes: [api, ui]
+typeScopes:
+  feat:
+    scope: required
+  ci:
+    scope: forbidden
+  build:
+    scopes: [deps]
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.type_scopes["feat"].scope, ScopePolicy::Required);
+        assert_eq!(config.type_scopes["feat"].scopes, None);
+        assert_eq!(config.type_scopes["ci"].scope, ScopePolicy::Forbidden);
+        assert_eq!(config.type_scopes["build"].scope, ScopePolicy::Optional);
+        assert_eq!(
+            config.type_scopes["build"].scopes,
+            Some(vec!["deps".to_string()])
+        );
+        assert!(Config::default().type_scopes.is_empty());
+    }
+
+    #[test]
+    fn test_description_style_parsing() {
+        let yaml = r#"
+description:
+  case: lower-first
+  noTrailingPeriod: true
+  maxLength: 50
+  imperative: true
+  noVague: true
+headerMaxLength: 72
+lengthUnit: graphemes
+mode: spec-strict
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.description.case, DescriptionCase::LowerFirst);
+        assert!(config.description.no_trailing_period);
+        assert_eq!(config.description.min_length, None);
+        assert_eq!(config.description.max_length, Some(50));
+        assert!(config.description.imperative);
+        assert!(!config.description.no_repeated_type);
+        assert!(config.description.no_vague);
+        assert_eq!(config.header_max_length, Some(72));
+        assert_eq!(config.length_unit, LengthUnit::Graphemes);
+        assert_eq!(Config::default().length_unit, LengthUnit::Width);
+        assert_eq!(config.mode, ParserMode::SpecStrict);
+        assert_eq!(Config::default().mode, ParserMode::HouseStyle);
+        assert_eq!(Config::default().description.case, DescriptionCase::Any);
+    }
+
     #[test]
     fn test_toml_parsing() {
         let toml_str = r#"
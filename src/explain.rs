@@ -0,0 +1,627 @@
+// Long-form explanations of error codes, printed by `conventional-prs explain`
+
+use crate::parser::ParseErrorKind;
+
+const SPEC_URL: &str = "https://www.conventionalcommits.org/en/v1.0.0/#specification";
+
+/// `docs/rules.md` in the repository, with one section per code
+const RULES_DOC_URL: &str = "https://github.com/scarf005/conventional-prs/blob/main/docs/rules.md";
+
+/// A numbered clause of the Conventional Commits 1.0.0 specification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpecClause {
+    pub number: u8,
+    pub text: &'static str,
+}
+
+const CLAUSE_1: SpecClause = SpecClause {
+    number: 1,
+    text: "Commits MUST be prefixed with a type, which consists of a noun, feat, fix, etc., followed by the OPTIONAL scope, OPTIONAL !, and REQUIRED terminal colon and space.",
+};
+const CLAUSE_4: SpecClause = SpecClause {
+    number: 4,
+    text: "A scope MAY be provided after a type. A scope MUST consist of a noun describing a section of the codebase surrounded by parenthesis, e.g., fix(parser):",
+};
+const CLAUSE_5: SpecClause = SpecClause {
+    number: 5,
+    text: "A description MUST immediately follow the colon and space after the type/scope prefix.",
+};
+const CLAUSE_6: SpecClause = SpecClause {
+    number: 6,
+    text: "A longer commit body MAY be provided after the short description, providing additional contextual information about the code changes. The body MUST begin one blank line after the description.",
+};
+const CLAUSE_8: SpecClause = SpecClause {
+    number: 8,
+    text: "One or more footers MAY be provided one blank line after the body. Each footer MUST consist of a word token, followed by either a :<space> or <space># separator, followed by a string value.",
+};
+const CLAUSE_9: SpecClause = SpecClause {
+    number: 9,
+    text: "A footer's token MUST use - in place of whitespace characters, e.g., Acked-by. An exception is made for BREAKING CHANGE, which MAY also be used as a token.",
+};
+const CLAUSE_14: SpecClause = SpecClause {
+    number: 14,
+    text: "Types other than feat and fix MAY be used in your commit messages.",
+};
+
+/// Documentation for one error code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Explanation {
+    /// Stable code, see [`ParseErrorKind::code`]
+    pub code: &'static str,
+    /// Stable kind name, see [`ParseErrorKind::name`]
+    pub name: &'static str,
+    /// The rule in one line, independent of the input
+    pub summary: &'static str,
+    pub description: &'static str,
+    /// Messages that trigger the error, with the default types and `scopes: [api, ui]`
+    pub invalid: &'static [&'static str],
+    pub valid: &'static [&'static str],
+    /// The clause the rule enforces; `None` for house-style rules beyond the spec
+    pub spec: Option<SpecClause>,
+}
+
+/// Every error code, in code order
+pub const EXPLANATIONS: &[Explanation] = &[
+    Explanation {
+        code: "CP0001",
+        name: "invalid-type",
+        summary: "Commit type must be one of the configured types",
+        description: "The type before the scope and colon is not in the configured `types` list. The spec allows any noun as a type, so projects restrict them to keep changelogs and version bumps predictable.",
+        invalid: &["feta: add login", "feature: add login"],
+        valid: &["feat: add login"],
+        spec: Some(CLAUSE_14),
+    },
+    Explanation {
+        code: "CP0002",
+        name: "invalid-scope",
+        summary: "Scope must be one of the configured scopes",
+        description: "The scope inside the parentheses is not in the configured `scopes` list. Scopes are optional, but when the configuration lists them only those are accepted. The list may contain patterns such as `pkg-*` or `{web,mobile}-ui`. Separate several scopes with commas.",
+        invalid: &["feat(auth): add login", "feat(api, auth): add login"],
+        valid: &[
+            "feat(api): add login",
+            "feat(api, ui): add login",
+            "feat: add login",
+        ],
+        spec: Some(CLAUSE_4),
+    },
+    Explanation {
+        code: "CP0003",
+        name: "type-used-as-scope",
+        summary: "A commit type cannot be used as a scope",
+        description: "The scope is one of the commit types. This usually means the type was written twice, or the intended type was put in the parentheses.",
+        invalid: &["feat(fix): handle empty body"],
+        valid: &["fix(api): handle empty body"],
+        spec: Some(CLAUSE_4),
+    },
+    Explanation {
+        code: "CP0004",
+        name: "missing-closing-paren",
+        summary: "Scope must be closed with ')'",
+        description: "A scope was opened with '(' but never closed, so the rest of the header was read as the scope.",
+        invalid: &["feat(api: add login"],
+        valid: &["feat(api): add login"],
+        spec: Some(CLAUSE_4),
+    },
+    Explanation {
+        code: "CP0005",
+        name: "missing-separator",
+        summary: "Type and description must be separated by ': '",
+        description: "The type or scope is not followed by the ': ' separator before the description.",
+        invalid: &[],
+        valid: &["feat: add login"],
+        spec: Some(CLAUSE_1),
+    },
+    Explanation {
+        code: "CP0006",
+        name: "missing-description",
+        summary: "Header must have a description",
+        description: "Nothing follows the colon. The description is a short summary of the change and is required.",
+        invalid: &["feat:", "feat(api):"],
+        valid: &["feat: add login"],
+        spec: Some(CLAUSE_5),
+    },
+    Explanation {
+        code: "CP0007",
+        name: "empty-type",
+        summary: "Header must start with a commit type",
+        description: "The header does not start with a type. Types consist of letters, digits and '-', and come before the optional scope.",
+        invalid: &[": add login", "(api): add login"],
+        valid: &["feat: add login", "feat(api): add login"],
+        spec: Some(CLAUSE_1),
+    },
+    Explanation {
+        code: "CP0008",
+        name: "empty-scope",
+        summary: "Parentheses must contain a scope",
+        description: "The parentheses after the type are empty. Either name a scope or leave the parentheses out.",
+        invalid: &["feat(): add login"],
+        valid: &["feat(api): add login", "feat: add login"],
+        spec: Some(CLAUSE_4),
+    },
+    Explanation {
+        code: "CP0009",
+        name: "unexpected-char",
+        summary: "Header must not contain unexpected characters",
+        description: "The header contains a character that is not allowed at this position.",
+        invalid: &[],
+        valid: &["feat: add login"],
+        spec: Some(CLAUSE_1),
+    },
+    Explanation {
+        code: "CP0010",
+        name: "parse-error",
+        summary: "Header must follow 'type(scope): description'",
+        description: "The header could not be parsed. It must have the form 'type(scope)!: description', where the scope and '!' are optional.",
+        invalid: &[],
+        valid: &["feat(api)!: drop v1 endpoints"],
+        spec: Some(CLAUSE_1),
+    },
+    Explanation {
+        code: "CP0011",
+        name: "extra-space-before-colon",
+        summary: "No whitespace before the colon",
+        description: "There is whitespace between the type, scope or '!' and the colon. The colon must follow them directly.",
+        invalid: &["feat : add login", "feat(api) : add login"],
+        valid: &["feat: add login", "feat(api): add login"],
+        spec: Some(CLAUSE_1),
+    },
+    Explanation {
+        code: "CP0012",
+        name: "extra-space-after-colon",
+        summary: "Exactly one space after the colon",
+        description: "The colon is followed by more than one space. The description must come right after a single space.",
+        invalid: &["feat:  add login"],
+        valid: &["feat: add login"],
+        spec: Some(CLAUSE_5),
+    },
+    Explanation {
+        code: "CP0013",
+        name: "missing-colon",
+        summary: "Type or scope must be followed by ':'",
+        description: "The type or scope is not followed by a colon, so the header has no separator between the prefix and the description.",
+        invalid: &["feat add login", "feat(api) - add login"],
+        valid: &["feat: add login", "feat(api): add login"],
+        spec: Some(CLAUSE_1),
+    },
+    Explanation {
+        code: "CP0014",
+        name: "missing-space",
+        summary: "Colon must be followed by a space",
+        description: "The colon is directly followed by the description. The spec requires a colon and a space between the prefix and the description.",
+        invalid: &["feat:add login"],
+        valid: &["feat: add login"],
+        spec: Some(CLAUSE_1),
+    },
+    Explanation {
+        code: "CP0015",
+        name: "trailing-spaces",
+        summary: "Header must not end with whitespace",
+        description: "The header ends with spaces. They are invisible in most tools and end up in changelogs and squash-merge titles.",
+        invalid: &["feat: add login  "],
+        valid: &["feat: add login"],
+        spec: None,
+    },
+    Explanation {
+        code: "CP0016",
+        name: "extra-space-after-open-paren",
+        summary: "No whitespace after '('",
+        description: "The scope starts with whitespace. Scopes are compared literally, so write them without padding.",
+        invalid: &["feat( api): add login"],
+        valid: &["feat(api): add login"],
+        spec: None,
+    },
+    Explanation {
+        code: "CP0017",
+        name: "extra-space-before-close-paren",
+        summary: "No whitespace before ')'",
+        description: "The scope ends with whitespace. Scopes are compared literally, so write them without padding.",
+        invalid: &["feat(api ): add login"],
+        valid: &["feat(api): add login"],
+        spec: None,
+    },
+    Explanation {
+        code: "CP0018",
+        name: "missing-blank-line-after-header",
+        summary: "Header must be followed by a blank line",
+        description: "The line after the header is not empty. Git and the spec treat the first paragraph as the subject, so the body must be separated from the header by a blank line.",
+        invalid: &["feat: add login\nUses OAuth."],
+        valid: &["feat: add login\n\nUses OAuth."],
+        spec: Some(CLAUSE_6),
+    },
+    Explanation {
+        code: "CP0019",
+        name: "missing-trailer-separator",
+        summary: "Trailers must use 'Token: value' or 'Token #value'",
+        description: "A line in the trailer block at the end of the message is neither a trailer nor an indented continuation of the previous one.",
+        invalid: &["feat: add login\n\nRefs: #42\nAcked-by Carol"],
+        valid: &["feat: add login\n\nRefs: #42\nAcked-by: Carol"],
+        spec: Some(CLAUSE_8),
+    },
+    Explanation {
+        code: "CP0020",
+        name: "trailer-token-with-spaces",
+        summary: "Trailer tokens must not contain whitespace",
+        description: "A trailer token contains whitespace. Tokens use '-' instead, which tells trailers apart from body text. 'BREAKING CHANGE' is the only exception.",
+        invalid: &["feat: add login\n\nReviewed by: Alice\nRefs: #42"],
+        valid: &[
+            "feat: add login\n\nReviewed-by: Alice\nRefs: #42",
+            "feat: add login\n\nBREAKING CHANGE: sessions expire",
+        ],
+        spec: Some(CLAUSE_9),
+    },
+    Explanation {
+        code: "CP0021",
+        name: "description-case",
+        summary: "Description must start with the configured case",
+        description: "The first letter of the description has the wrong case. Set `description.case` to `lower-first` (descriptions may still start with an acronym such as 'API') or `sentence`.",
+        invalid: &["feat: Add login"],
+        valid: &["feat: add login", "feat: API keys for login"],
+        spec: None,
+    },
+    Explanation {
+        code: "CP0022",
+        name: "description-trailing-period",
+        summary: "Description must not end with a period",
+        description: "The description ends with a period. Headers are titles, not sentences. Enabled by `description.noTrailingPeriod`.",
+        invalid: &["feat: add login."],
+        valid: &["feat: add login"],
+        spec: None,
+    },
+    Explanation {
+        code: "CP0023",
+        name: "description-too-short",
+        summary: "Description is shorter than `description.minLength`",
+        description: "The description is shorter than `description.minLength`, so it is unlikely to say what changed. Lengths are in display columns unless `lengthUnit` is `graphemes`.",
+        invalid: &["fix: typo"],
+        valid: &["fix: correct typo in login form"],
+        spec: None,
+    },
+    Explanation {
+        code: "CP0024",
+        name: "description-too-long",
+        summary: "Description is longer than `description.maxLength`",
+        description: "The description is longer than `description.maxLength`. Keep the header short and move details to the body; the fix drops the words past the limit. Lengths are in display columns unless `lengthUnit` is `graphemes`.",
+        invalid: &["feat: add login with passwords, magic links, passkeys and single sign-on"],
+        valid: &[
+            "feat: add login\n\nSupports passwords, magic links, passkeys and single sign-on.",
+        ],
+        spec: None,
+    },
+    Explanation {
+        code: "CP0025",
+        name: "header-too-long",
+        summary: "Header is longer than `headerMaxLength`",
+        description: "The header is longer than `headerMaxLength`, not counting `fixup!` and `squash!` prefixes. GitHub and git tools cut long headers off by their displayed width, so lengths are in display columns (CJK characters and emoji count as 2) unless `lengthUnit` is `graphemes`.",
+        invalid: &["feat(api): add login with passwords, magic links, passkeys and single sign-on"],
+        valid: &["feat(api): add login"],
+        spec: None,
+    },
+    Explanation {
+        code: "CP0026",
+        name: "description-not-imperative",
+        summary: "Description must start with an imperative verb",
+        description: "The description starts with a known English verb in the `-s`, `-ed` or `-ing` form, followed by its object. Write it as a command, so it completes \"If applied, this commit will ...\". Enabled by `description.imperative`; the fix replaces the verb with its imperative form.",
+        invalid: &["feat: added login", "feat: adding login"],
+        valid: &["feat: add login"],
+        spec: None,
+    },
+    Explanation {
+        code: "CP0027",
+        name: "description-repeats-type",
+        summary: "Description must not repeat the type",
+        description: "The description starts with the commit type, or a form of it, so the header says the same thing twice. Enabled by `description.noRepeatedType`; the fix drops the repeated word.",
+        invalid: &["fix: fix crash on start"],
+        valid: &["fix: handle crash on start"],
+        spec: None,
+    },
+    Explanation {
+        code: "CP0028",
+        name: "vague-description",
+        summary: "Description must say what changed",
+        description: "The description is made of vague words only, like 'update', 'changes' or 'wip', so readers of the history learn nothing from it. Enabled by `description.noVague`.",
+        invalid: &["chore: minor changes", "feat: wip"],
+        valid: &["chore: bump serde to 1.0.200"],
+        spec: None,
+    },
+    Explanation {
+        code: "CP0029",
+        name: "scope-required",
+        summary: "Type requires a scope",
+        description: "The type is set to `scope: required` under `typeScopes`, but the header has no scope.",
+        invalid: &["perf: cache sessions"],
+        valid: &["perf(api): cache sessions"],
+        spec: None,
+    },
+    Explanation {
+        code: "CP0030",
+        name: "scope-forbidden",
+        summary: "Type does not take a scope",
+        description: "The type is set to `scope: forbidden` under `typeScopes`, but the header has a scope. The fix removes it.",
+        invalid: &["ci(api): cache cargo registry"],
+        valid: &["ci: cache cargo registry"],
+        spec: None,
+    },
+    Explanation {
+        code: "CP0031",
+        name: "scope-not-allowed-for-type",
+        summary: "Scope is not allowed with this type",
+        description: "The scope is valid for other types, but not in the `scopes` list of this type under `typeScopes`.",
+        invalid: &["feat(deps): bump serde"],
+        valid: &["build(deps): bump serde"],
+        spec: None,
+    },
+    Explanation {
+        code: "CP0032",
+        name: "type-alias",
+        summary: "Type is an alias; use the canonical type",
+        description: "The type is listed under `typeAliases`, e.g. `enhancement` for `feat`. The fix replaces it with the canonical type. Set the rule to `warn` or `off` to accept aliases; parsed headers always carry the canonical type.",
+        invalid: &["enhancement: add login"],
+        valid: &["feat: add login"],
+        spec: None,
+    },
+    Explanation {
+        code: "CP0033",
+        name: "scope-alias",
+        summary: "Scope is an alias; use the canonical scope",
+        description: "The scope is listed under `scopeAliases`, e.g. `frontend` for `ui`. The fix replaces it with the canonical scope. Set the rule to `warn` or `off` to accept aliases; parsed headers always carry the canonical scope.",
+        invalid: &["feat(frontend): add login"],
+        valid: &["feat(ui): add login"],
+        spec: None,
+    },
+    Explanation {
+        code: "CP0034",
+        name: "deprecated-type",
+        summary: "Type is deprecated",
+        description: "The type is listed under `deprecated.types`. It is accepted with a warning until its `until` date has passed and rejected after it. The fix replaces it with its `replacement`, if one is configured.",
+        invalid: &["wip: add login"],
+        valid: &["feat: add login"],
+        spec: None,
+    },
+    Explanation {
+        code: "CP0035",
+        name: "deprecated-scope",
+        summary: "Scope is deprecated",
+        description: "The scope is listed under `deprecated.scopes`, e.g. `legacy` after it was renamed to `ui`. It is accepted with a warning until its `until` date has passed and rejected after it. The fix replaces it with its `replacement`, if one is configured.",
+        invalid: &["feat(legacy): add login"],
+        valid: &["feat(ui): add login"],
+        spec: None,
+    },
+    Explanation {
+        code: "CP0036",
+        name: "type-case",
+        summary: "Type must be written as configured",
+        description: "The type matches a configured type except for its case. Clause 15 of the spec makes types case-insensitive, so this is off with `mode: spec-strict`. The fix writes the configured spelling, which parsed headers always carry.",
+        invalid: &["Feat: add login", "FIX: handle empty input"],
+        valid: &["feat: add login"],
+        spec: None,
+    },
+];
+
+impl Explanation {
+    /// Look up an explanation by code (`CP0014`, case-insensitive) or kind name (`missing-space`)
+    pub fn find(query: &str) -> Option<&'static Explanation> {
+        let query = query.trim();
+        EXPLANATIONS.iter().find(|explanation| {
+            explanation.code.eq_ignore_ascii_case(query) || explanation.name == query
+        })
+    }
+
+    /// Explanation of a built-in error kind; `None` for diagnostics of custom rules
+    pub fn of(kind: &ParseErrorKind) -> Option<&'static Explanation> {
+        match kind {
+            ParseErrorKind::Custom { .. } => None,
+            kind => Self::find(kind.code()),
+        }
+    }
+
+    /// Plain-text explanation with examples and the spec clause
+    pub fn render(&self) -> String {
+        let mut output = format!(
+            "{} {}: {}\n\n{}\n",
+            self.code, self.name, self.summary, self.description
+        );
+
+        let mut examples = |heading: &str, messages: &[&str]| {
+            if !messages.is_empty() {
+                output.push_str(&format!("\n{heading}:\n"));
+                for message in messages {
+                    output.push_str(&format!("    {message:?}\n"));
+                }
+            }
+        };
+        examples("Invalid", self.invalid);
+        examples("Valid", self.valid);
+
+        match self.spec {
+            Some(clause) => output.push_str(&format!(
+                "\nConventional Commits 1.0.0, clause {}:\n    {}\n    {SPEC_URL}\n",
+                clause.number, clause.text
+            )),
+            None => output.push_str(
+                "\nThis is a house-style rule: the Conventional Commits specification does not require it.\n",
+            ),
+        }
+        output
+    }
+
+    /// Link to this code's section of `docs/rules.md`
+    pub fn help_uri(&self) -> String {
+        format!("{RULES_DOC_URL}#{}", self.code.to_lowercase())
+    }
+
+    /// Markdown section of `docs/rules.md`, headed by the code so it can be linked to
+    fn render_markdown(&self) -> String {
+        let mut output = format!(
+            "## {}\n\n`{}`: {}\n\n{}\n",
+            self.code, self.name, self.summary, self.description
+        );
+
+        let mut examples = |heading: &str, messages: &[&str]| {
+            if !messages.is_empty() {
+                output.push_str(&format!("\n{heading}:\n"));
+                for message in messages {
+                    output.push_str(&format!("\n```text\n{message}\n```\n"));
+                }
+            }
+        };
+        examples("Invalid", self.invalid);
+        examples("Valid", self.valid);
+
+        match self.spec {
+            Some(clause) => output.push_str(&format!(
+                "\n[Conventional Commits 1.0.0]({SPEC_URL}), clause {}:\n\n> {}\n",
+                clause.number, clause.text
+            )),
+            None => output.push_str(
+                "\nThis is a house-style rule: the Conventional Commits specification does not require it.\n",
+            ),
+        }
+        output
+    }
+}
+
+/// Contents of `docs/rules.md`: every code with its explanation
+pub fn rules_markdown() -> String {
+    let mut output = String::from(
+        "# Error codes\n\n<!-- Generated from src/explain.rs by `UPDATE_DOCS=1 cargo test`. Do not edit. -->\n",
+    );
+    for explanation in EXPLANATIONS {
+        output.push('\n');
+        output.push_str(&explanation.render_markdown());
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{
+        Config, DeprecatedConfig, Deprecation, DescriptionCase, DescriptionConfig, ScopePolicy,
+        TypeScopes,
+    };
+    use crate::lint::Linter;
+
+    #[test]
+    fn test_codes_are_unique_and_ordered() {
+        for (idx, explanation) in EXPLANATIONS.iter().enumerate() {
+            assert_eq!(explanation.code, format!("CP{:04}", idx + 1));
+        }
+    }
+
+    #[test]
+    fn test_examples_match_their_code() {
+        let linter = Linter::from_config(&Config {
+            scopes: Some(vec!["api".to_string(), "ui".to_string()]),
+            description: DescriptionConfig {
+                case: DescriptionCase::LowerFirst,
+                no_trailing_period: true,
+                min_length: Some(5),
+                max_length: Some(50),
+                imperative: true,
+                no_repeated_type: true,
+                no_vague: true,
+            },
+            type_scopes: [
+                ("perf", ScopePolicy::Required, None),
+                ("ci", ScopePolicy::Forbidden, None),
+                (
+                    "build",
+                    ScopePolicy::Optional,
+                    Some(vec!["api", "ui", "deps"]),
+                ),
+            ]
+            .into_iter()
+            .map(|(name, scope, scopes)| {
+                let scopes = scopes.map(|scopes| scopes.into_iter().map(String::from).collect());
+                (name.to_string(), TypeScopes { scope, scopes })
+            })
+            .collect(),
+            type_aliases: [("enhancement".to_string(), "feat".to_string())].into(),
+            scope_aliases: [("frontend".to_string(), "ui".to_string())].into(),
+            // Expired, so the examples are errors rather than warnings
+            deprecated: DeprecatedConfig {
+                types: [(
+                    "wip".to_string(),
+                    Deprecation {
+                        replacement: None,
+                        until: Some("2020-01-01".to_string()),
+                    },
+                )]
+                .into(),
+                scopes: [(
+                    "legacy".to_string(),
+                    Deprecation {
+                        replacement: Some("ui".to_string()),
+                        until: Some("2020-01-01".to_string()),
+                    },
+                )]
+                .into(),
+            },
+            header_max_length: Some(72),
+            ..Config::default()
+        });
+
+        for explanation in EXPLANATIONS {
+            for message in explanation.invalid {
+                let result = linter.lint(message);
+                let errors = result.errors().unwrap_or_default();
+                assert!(
+                    errors
+                        .iter()
+                        .any(|error| error.kind.code() == explanation.code
+                            && error.kind.name() == explanation.name),
+                    "{message:?} should report {}: {errors:?}",
+                    explanation.code
+                );
+            }
+            for message in explanation.valid {
+                assert!(linter.lint(message).is_ok(), "{message:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_by_code_or_name() {
+        assert_eq!(Explanation::find("cp0014").unwrap().name, "missing-space");
+        assert_eq!(Explanation::find("missing-space").unwrap().code, "CP0014");
+        assert!(Explanation::find("CP9999").is_none());
+        assert_eq!(
+            Explanation::of(&ParseErrorKind::MissingDescription)
+                .unwrap()
+                .code,
+            "CP0006"
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let text = Explanation::find("CP0014").unwrap().render();
+        assert!(text.starts_with("CP0014 missing-space: Colon must be followed by a space\n"));
+        assert!(text.contains("Invalid:\n    \"feat:add login\"\n"));
+        assert!(text.contains("clause 1:"));
+
+        let text = Explanation::find("trailing-spaces").unwrap().render();
+        assert!(text.contains("house-style rule"));
+    }
+
+    #[test]
+    fn test_help_uri_links_to_the_code() {
+        assert_eq!(
+            Explanation::find("CP0014").unwrap().help_uri(),
+            format!("{RULES_DOC_URL}#cp0014")
+        );
+    }
+
+    #[test]
+    fn test_rules_doc_is_up_to_date() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/docs/rules.md");
+        if std::env::var_os("UPDATE_DOCS").is_some() {
+            std::fs::write(path, rules_markdown()).unwrap();
+        }
+        let doc = std::fs::read_to_string(path).unwrap_or_default();
+        assert!(
+            doc == rules_markdown(),
+            "docs/rules.md is out of date; run `UPDATE_DOCS=1 cargo test`"
+        );
+    }
+}
@@ -0,0 +1,407 @@
+// Machine-applicable fix suggestions for parse errors
+
+use crate::config::DescriptionCase;
+use crate::glob;
+use crate::parser::{ParseError, ParseErrorKind};
+use crate::report::similar_candidates;
+
+/// How safely a suggestion can be applied without review, following rustc's levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Applicability {
+    /// The fix is unambiguous (e.g. deleting extra spaces)
+    MachineApplicable,
+    /// The fix is a best guess (e.g. a did-you-mean type) and should be reviewed
+    MaybeIncorrect,
+    /// The fix inserts a placeholder such as `<description>` that must be filled in
+    HasPlaceholders,
+}
+
+/// A text edit that resolves a parse error. The span is in characters, like error spans.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    pub span: std::ops::Range<usize>,
+    pub replacement: String,
+    pub applicability: Applicability,
+}
+
+impl Suggestion {
+    pub fn new(
+        span: std::ops::Range<usize>,
+        replacement: impl Into<String>,
+        applicability: Applicability,
+    ) -> Self {
+        Self {
+            span,
+            replacement: replacement.into(),
+            applicability,
+        }
+    }
+
+    /// Insert `text` at a character offset
+    pub fn insert(at: usize, text: &str, applicability: Applicability) -> Self {
+        Self::new(at..at, text, applicability)
+    }
+
+    /// Delete the characters in `span`
+    pub fn remove(span: std::ops::Range<usize>) -> Self {
+        Self::new(span, "", Applicability::MachineApplicable)
+    }
+
+    /// Apply this edit to `input`, returning the edited text
+    pub fn apply(&self, input: &str) -> String {
+        let byte_offset = |char_idx: usize| {
+            input
+                .char_indices()
+                .nth(char_idx)
+                .map_or(input.len(), |(idx, _)| idx)
+        };
+        let start = byte_offset(self.span.start);
+        let end = byte_offset(self.span.end).max(start);
+
+        let mut output = String::with_capacity(input.len() + self.replacement.len());
+        output.push_str(&input[..start]);
+        output.push_str(&self.replacement);
+        output.push_str(&input[end..]);
+        output
+    }
+}
+
+/// Compute the fix for an error, using `input` (the text the span points into) for context.
+/// Returns `None` when there is no sensible edit, e.g. an unknown scope with no close match.
+pub fn suggest(input: &str, error: &ParseError) -> Option<Suggestion> {
+    let span = error.span.clone();
+    let text: String = input
+        .chars()
+        .skip(span.start)
+        .take(span.end.saturating_sub(span.start))
+        .collect();
+
+    match &error.kind {
+        ParseErrorKind::InvalidType { .. }
+        | ParseErrorKind::InvalidScope { .. }
+        | ParseErrorKind::ScopeNotAllowedForType { .. } => similar_candidates(&error.kind)
+            .into_iter()
+            .next()
+            .map(|candidate| Suggestion::new(span, candidate.value, Applicability::MaybeIncorrect)),
+        ParseErrorKind::TypeUsedAsScope { .. } | ParseErrorKind::GenericParseError(_) => None,
+        // Rules attach their own suggestions
+        ParseErrorKind::Custom { .. } => None,
+        ParseErrorKind::MissingClosingParen => {
+            // The scope runs to the end of the header; close it before the colon if there is one
+            let at = text.find(':').map_or(span.end, |idx| {
+                span.start + text[..idx].trim_end().chars().count()
+            });
+            Some(Suggestion::insert(
+                at,
+                ")",
+                Applicability::MachineApplicable,
+            ))
+        }
+        ParseErrorKind::MissingSeparator => Some(Suggestion::insert(
+            span.start,
+            ": ",
+            Applicability::MachineApplicable,
+        )),
+        ParseErrorKind::MissingDescription => Some(Suggestion::insert(
+            span.start,
+            "<description>",
+            Applicability::HasPlaceholders,
+        )),
+        ParseErrorKind::EmptyType => Some(Suggestion::insert(
+            span.start,
+            "<type>",
+            Applicability::HasPlaceholders,
+        )),
+        ParseErrorKind::UnexpectedChar(_) => {
+            Some(Suggestion::new(span, "", Applicability::MaybeIncorrect))
+        }
+        ParseErrorKind::EmptyScope
+        | ParseErrorKind::ExtraSpaceBeforeColon
+        | ParseErrorKind::ExtraSpaceAfterColon
+        | ParseErrorKind::TrailingSpaces
+        | ParseErrorKind::ExtraSpaceAfterOpenParen
+        | ParseErrorKind::ExtraSpaceBeforeCloseParen => Some(Suggestion::remove(span)),
+        ParseErrorKind::MissingColon => Some(Suggestion::insert(
+            span.start,
+            ":",
+            Applicability::MachineApplicable,
+        )),
+        ParseErrorKind::MissingSpace => Some(Suggestion::insert(
+            span.end,
+            " ",
+            Applicability::MachineApplicable,
+        )),
+        ParseErrorKind::MissingBlankLineAfterHeader => Some(Suggestion::insert(
+            span.start,
+            "\n",
+            Applicability::MachineApplicable,
+        )),
+        ParseErrorKind::MissingTrailerSeparator => {
+            // `Token value` -> `Token: value`
+            let token_len = text.find(char::is_whitespace)?;
+            let (token, rest) = text.split_at(token_len);
+            let gap = rest.len() - rest.trim_start().len();
+            if token.is_empty() || !token.chars().all(|c| c.is_alphanumeric() || c == '-') {
+                return None;
+            }
+            let start = span.start + token.chars().count();
+            Some(Suggestion::new(
+                start..start + gap,
+                ": ",
+                Applicability::MaybeIncorrect,
+            ))
+        }
+        ParseErrorKind::TrailerTokenWithSpaces { token } => Some(Suggestion::new(
+            span,
+            token.split_whitespace().collect::<Vec<_>>().join("-"),
+            Applicability::MachineApplicable,
+        )),
+        ParseErrorKind::DescriptionCase { expected } => match expected {
+            DescriptionCase::Sentence => Some(Suggestion::new(
+                span,
+                text.to_uppercase(),
+                Applicability::MachineApplicable,
+            )),
+            // Proper nouns are capitalized on purpose
+            DescriptionCase::LowerFirst | DescriptionCase::Any => Some(Suggestion::new(
+                span,
+                text.to_lowercase(),
+                Applicability::MaybeIncorrect,
+            )),
+        },
+        ParseErrorKind::DescriptionTrailingPeriod => Some(Suggestion::remove(span)),
+        ParseErrorKind::DescriptionTooShort { .. } => Some(Suggestion::insert(
+            span.end,
+            " <details>",
+            Applicability::HasPlaceholders,
+        )),
+        // The cut depends on the length unit, so length rules attach their own suggestions
+        ParseErrorKind::DescriptionTooLong { .. } | ParseErrorKind::HeaderTooLong { .. } => None,
+        ParseErrorKind::DescriptionNotImperative { imperative, .. } => {
+            // Keep a capitalized first letter, which the case rule checks separately
+            let replacement = match text.chars().next() {
+                Some(first) if first.is_uppercase() => {
+                    let mut chars = imperative.chars();
+                    chars
+                        .next()
+                        .map(|c| c.to_uppercase().chain(chars).collect())
+                        .unwrap_or_default()
+                }
+                _ => imperative.clone(),
+            };
+            Some(Suggestion::new(
+                span,
+                replacement,
+                Applicability::MaybeIncorrect,
+            ))
+        }
+        ParseErrorKind::DescriptionRepeatsType { .. } => {
+            // Drop the repeated word and the whitespace after it, if anything follows
+            let rest: String = input.chars().skip(span.end).collect();
+            let line_rest = rest.split('\n').next().unwrap_or_default();
+            let gap = line_rest.chars().take_while(|c| c.is_whitespace()).count();
+            (gap > 0 && !line_rest.trim().is_empty()).then(|| {
+                Suggestion::new(
+                    span.start..span.end + gap,
+                    "",
+                    Applicability::MaybeIncorrect,
+                )
+            })
+        }
+        ParseErrorKind::VagueDescription => None,
+        ParseErrorKind::TypeAlias { canonical, .. }
+        | ParseErrorKind::ScopeAlias { canonical, .. }
+        | ParseErrorKind::TypeCase {
+            expected: canonical,
+            ..
+        } => Some(Suggestion::new(
+            span,
+            canonical.clone(),
+            Applicability::MachineApplicable,
+        )),
+        ParseErrorKind::DeprecatedType { replacement, .. }
+        | ParseErrorKind::DeprecatedScope { replacement, .. } => {
+            replacement.as_ref().map(|replacement| {
+                Suggestion::new(span, replacement.clone(), Applicability::MachineApplicable)
+            })
+        }
+        ParseErrorKind::ScopeRequired { expected, .. } => {
+            // With a single allowed scope there is nothing to choose
+            let (scope, applicability) = match expected.as_slice() {
+                [only] if !glob::is_pattern(only) => (only.as_str(), Applicability::MaybeIncorrect),
+                _ => ("<scope>", Applicability::HasPlaceholders),
+            };
+            Some(Suggestion::insert(
+                span.end,
+                &format!("({scope})"),
+                applicability,
+            ))
+        }
+        ParseErrorKind::ScopeForbidden { .. } => {
+            Some(Suggestion::new(span, "", Applicability::MaybeIncorrect))
+        }
+    }
+}
+
+/// Attach a suggestion to every error that does not have one yet
+pub(crate) fn attach_suggestions(input: &str, errors: &mut [ParseError]) {
+    for error in errors.iter_mut().filter(|error| error.suggestion.is_none()) {
+        error.suggestion = suggest(input, error);
+    }
+}
+
+/// Apply one suggestion at a time and re-check with `check`, until no diagnostic has a
+/// suggestion without placeholders left or a fix changes nothing.
+pub(crate) fn fix_until_stable(input: &str, check: impl Fn(&str) -> Vec<ParseError>) -> String {
+    let mut current = input.to_string();
+
+    // Apply one edit per round and re-check, since fixes for one error often
+    // resolve (or move) the errors after it
+    for _ in 0..=current.chars().count() {
+        let diagnostics = check(&current);
+        let Some(suggestion) = diagnostics
+            .iter()
+            .filter_map(|error| error.suggestion.as_ref())
+            .filter(|s| s.applicability != Applicability::HasPlaceholders)
+            .min_by_key(|s| s.span.start)
+        else {
+            break;
+        };
+
+        let fixed = suggestion.apply(&current);
+        if fixed == current {
+            break;
+        }
+        current = fixed;
+    }
+
+    current
+}
+
+/// Apply every non-overlapping suggestion that needs no placeholder in one pass.
+/// Unlike `ConventionalParser::fix_message` this does not re-parse, so it is a preview.
+pub(crate) fn apply_suggestions(input: &str, errors: &[ParseError]) -> String {
+    let mut suggestions: Vec<&Suggestion> = errors
+        .iter()
+        .filter_map(|error| error.suggestion.as_ref())
+        .filter(|s| s.applicability != Applicability::HasPlaceholders)
+        .collect();
+    suggestions.sort_by_key(|s| std::cmp::Reverse((s.span.start, s.span.end)));
+
+    // Apply right to left so earlier spans stay valid
+    let mut output = input.to_string();
+    let mut limit = usize::MAX;
+    for suggestion in suggestions {
+        if suggestion.span.end > limit {
+            continue;
+        }
+        output = suggestion.apply(&output);
+        limit = suggestion.span.start;
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn suggestion_for(input: &str, kind: ParseErrorKind, span: std::ops::Range<usize>) -> String {
+        let error = ParseError::new(kind, span);
+        suggest(input, &error)
+            .expect("expected a suggestion")
+            .apply(input)
+    }
+
+    #[test]
+    fn test_remove_extra_space_before_colon() {
+        let fixed = suggestion_for("feat  : x", ParseErrorKind::ExtraSpaceBeforeColon, 4..6);
+        assert_eq!(fixed, "feat: x");
+    }
+
+    #[test]
+    fn test_insert_missing_closing_paren_before_colon() {
+        let fixed = suggestion_for("feat(api: x", ParseErrorKind::MissingClosingParen, 4..11);
+        assert_eq!(fixed, "feat(api): x");
+    }
+
+    #[test]
+    fn test_invalid_type_uses_similar_candidate() {
+        let kind = ParseErrorKind::InvalidType {
+            found: "feta".to_string(),
+            expected: vec!["feat".to_string(), "fix".to_string()],
+            descriptions: std::collections::BTreeMap::new(),
+        };
+        let error = ParseError::new(kind, 0..4);
+        let suggestion = suggest("feta: x", &error).unwrap();
+        assert_eq!(suggestion.applicability, Applicability::MaybeIncorrect);
+        assert_eq!(suggestion.apply("feta: x"), "feat: x");
+    }
+
+    #[test]
+    fn test_invalid_scope_without_candidate_has_no_suggestion() {
+        let kind = ParseErrorKind::InvalidScope {
+            found: "zzz".to_string(),
+            expected: vec!["api".to_string()],
+        };
+        assert!(suggest("feat(zzz): x", &ParseError::new(kind, 5..8)).is_none());
+    }
+
+    #[test]
+    fn test_trailer_fixes() {
+        let fixed = suggestion_for(
+            "Signed-off-by Alice",
+            ParseErrorKind::MissingTrailerSeparator,
+            0..19,
+        );
+        assert_eq!(fixed, "Signed-off-by: Alice");
+
+        let kind = ParseErrorKind::TrailerTokenWithSpaces {
+            token: "Reviewed by".to_string(),
+        };
+        assert_eq!(
+            suggestion_for("Reviewed by: Bob", kind, 0..11),
+            "Reviewed-by: Bob"
+        );
+    }
+
+    #[test]
+    fn test_apply_suggestions_skips_overlaps_and_placeholders() {
+        let errors = [
+            ParseError::new(ParseErrorKind::ExtraSpaceBeforeColon, 4..6)
+                .with_suggestion(Suggestion::remove(4..6)),
+            ParseError::new(ParseErrorKind::UnexpectedChar('t'), 3..5)
+                .with_suggestion(Suggestion::remove(3..5)),
+            ParseError::new(ParseErrorKind::MissingDescription, 8..8).with_suggestion(
+                Suggestion::insert(8, "<description>", Applicability::HasPlaceholders),
+            ),
+            ParseError::new(ParseErrorKind::TrailingSpaces, 9..11)
+                .with_suggestion(Suggestion::remove(9..11)),
+        ];
+        assert_eq!(apply_suggestions("feat  : x  ", &errors), "feat: x");
+    }
+
+    #[test]
+    fn test_apply_uses_char_offsets() {
+        let suggestion = Suggestion::new(8..9, "", Applicability::MachineApplicable);
+        assert_eq!(suggestion.apply("feat: é  x"), "feat: é x");
+    }
+
+    #[test]
+    fn test_description_style_fixes() {
+        let kind = ParseErrorKind::DescriptionCase {
+            expected: DescriptionCase::LowerFirst,
+        };
+        assert_eq!(suggestion_for("feat: Add api", kind, 6..7), "feat: add api");
+
+        let kind = ParseErrorKind::DescriptionCase {
+            expected: DescriptionCase::Sentence,
+        };
+        assert_eq!(suggestion_for("feat: add api", kind, 6..7), "feat: Add api");
+
+        let kind = ParseErrorKind::DescriptionTrailingPeriod;
+        assert_eq!(
+            suggestion_for("feat: add api...", kind, 13..16),
+            "feat: add api"
+        );
+    }
+}
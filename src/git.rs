@@ -0,0 +1,234 @@
+// Git integration: commit enumeration via the local `git` binary and commit message files
+
+use crate::parser::ParseError;
+use std::process::Command;
+use thiserror::Error;
+
+const SCISSORS: &str = "------------------------ >8 ------------------------";
+
+#[derive(Debug, Error)]
+pub enum GitError {
+    #[error("Failed to run git: {0}")]
+    SpawnError(#[from] std::io::Error),
+    #[error("git {args} failed: {stderr}")]
+    CommandFailed { args: String, stderr: String },
+    #[error("git output is not valid UTF-8")]
+    InvalidUtf8(#[from] std::string::FromUtf8Error),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Commit {
+    pub sha: String,
+    pub message: String,
+}
+
+impl Commit {
+    pub fn short_sha(&self) -> &str {
+        &self.sha[..self.sha.len().min(7)]
+    }
+}
+
+/// Commits in a revision range such as `origin/main..HEAD`, oldest first
+pub fn commits_in_range(range: &str) -> Result<Vec<Commit>, GitError> {
+    log(&[], range)
+}
+
+/// The last `count` commits reachable from HEAD, oldest first
+pub fn last_commits(count: usize) -> Result<Vec<Commit>, GitError> {
+    log(&["-n", &count.to_string()], "HEAD")
+}
+
+/// `git log` over `revisions`. `--end-of-options` keeps a revision starting with `-` from
+/// being read as an option, and `--` keeps it from being read as a path.
+fn log(options: &[&str], revisions: &str) -> Result<Vec<Commit>, GitError> {
+    let mut args = vec!["log", "-z", "--reverse", "--format=%H%n%B"];
+    args.extend_from_slice(options);
+    args.extend(["--end-of-options", revisions, "--"]);
+
+    let output = Command::new("git").args(&args).output()?;
+    if !output.status.success() {
+        return Err(GitError::CommandFailed {
+            args: args.join(" "),
+            stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        });
+    }
+
+    Ok(parse_log_output(&String::from_utf8(output.stdout)?))
+}
+
+/// Parse `git log -z --format=%H%n%B` output: NUL-separated records of SHA, newline, message
+fn parse_log_output(output: &str) -> Vec<Commit> {
+    output
+        .split('\0')
+        .filter_map(|record| {
+            let record = record.trim_start_matches('\n');
+            let (sha, message) = record.split_once('\n').unwrap_or((record, ""));
+            (!sha.is_empty()).then(|| Commit {
+                sha: sha.to_string(),
+                message: message.trim_end().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// The comment prefix git uses in commit message files (`core.commentChar`, default '#')
+pub fn comment_char() -> String {
+    Command::new("git")
+        .args(["config", "--get", "core.commentChar"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty() && value != "auto")
+        .unwrap_or_else(|| "#".to_string())
+}
+
+/// A commit message file with git comments and the `git commit -v` scissors section removed.
+/// Keeps track of where each remaining line came from so spans can be mapped back.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleanedMessage {
+    pub message: String,
+    /// (char offset in `message`, char offset in the original file) for each kept line
+    line_offsets: Vec<(usize, usize)>,
+}
+
+impl CleanedMessage {
+    /// Clean a raw commit message the way `git commit --cleanup=strip` does: drop comment
+    /// lines and everything below the scissors line, strip trailing whitespace, and drop
+    /// leading and trailing blank lines.
+    pub fn new(raw: &str, comment_char: &str) -> Self {
+        let scissors = format!("{comment_char} {SCISSORS}");
+        let mut kept: Vec<(usize, &str)> = Vec::new();
+        let mut original_offset = 0;
+
+        for line in raw.split('\n') {
+            let line_start = original_offset;
+            original_offset += line.chars().count() + 1;
+
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            if line == scissors {
+                break;
+            }
+            if line.starts_with(comment_char) {
+                continue;
+            }
+            let line = line.trim_end();
+            if line.is_empty() && kept.is_empty() {
+                continue;
+            }
+            kept.push((line_start, line));
+        }
+
+        while kept.last().is_some_and(|(_, line)| line.is_empty()) {
+            kept.pop();
+        }
+
+        let mut message = String::new();
+        let mut line_offsets = Vec::with_capacity(kept.len());
+        let mut cleaned_offset = 0;
+        for (idx, (line_start, line)) in kept.iter().enumerate() {
+            if idx > 0 {
+                message.push('\n');
+                cleaned_offset += 1;
+            }
+            line_offsets.push((cleaned_offset, *line_start));
+            message.push_str(line);
+            cleaned_offset += line.chars().count();
+        }
+
+        Self {
+            message,
+            line_offsets,
+        }
+    }
+
+    fn original_offset(&self, offset: usize) -> usize {
+        self.line_offsets
+            .iter()
+            .rev()
+            .find(|(cleaned, _)| *cleaned <= offset)
+            .map_or(offset, |(cleaned, original)| original + (offset - cleaned))
+    }
+
+    /// Map a span in the cleaned message back to the original file
+    pub fn original_span(&self, span: std::ops::Range<usize>) -> std::ops::Range<usize> {
+        let start = self.original_offset(span.start);
+        let end = if span.end > span.start {
+            self.original_offset(span.end - 1) + 1
+        } else {
+            start
+        };
+        start..end
+    }
+
+    /// Map error and suggestion spans in the cleaned message back to the original file
+    pub fn original_errors(&self, errors: &[ParseError]) -> Vec<ParseError> {
+        errors
+            .iter()
+            .map(|error| {
+                let mut error = error.clone();
+                error.span = self.original_span(error.span);
+                if let Some(suggestion) = &mut error.suggestion {
+                    suggestion.span = self.original_span(suggestion.span.clone());
+                }
+                error
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_log_output() {
+        let output = "1111111aaaa\nfeat: add api\n\nBody text.\n\0\n2222222bbbb\nfix: typo\n\0";
+        let commits = parse_log_output(output);
+        assert_eq!(commits.len(), 2);
+        assert_eq!(commits[0].sha, "1111111aaaa");
+        assert_eq!(commits[0].short_sha(), "1111111");
+        assert_eq!(commits[0].message, "feat: add api\n\nBody text.");
+        assert_eq!(commits[1].message, "fix: typo");
+    }
+
+    #[test]
+    fn test_parse_empty_log_output() {
+        assert!(parse_log_output("").is_empty());
+    }
+
+    #[test]
+    fn test_range_is_never_an_option() {
+        // As an option, `--all` would list every commit; as a revision it does not exist
+        assert!(commits_in_range("--all").is_err());
+    }
+
+    #[test]
+    fn test_cleaned_message_strips_comments_and_scissors() {
+        let raw = "feat: add api\n# Please enter the commit message\n\nBody text.   \n#\n# ------------------------ >8 ------------------------\ndiff --git a/x b/x\n";
+        let cleaned = CleanedMessage::new(raw, "#");
+        assert_eq!(cleaned.message, "feat: add api\n\nBody text.");
+    }
+
+    #[test]
+    fn test_cleaned_message_custom_comment_char() {
+        let raw =
+            "; comment\nfix: typo\n; ------------------------ >8 ------------------------\nrest";
+        let cleaned = CleanedMessage::new(raw, ";");
+        assert_eq!(cleaned.message, "fix: typo");
+    }
+
+    #[test]
+    fn test_cleaned_message_maps_spans_to_original_lines() {
+        let raw = "# leading comment\nfature: typo\n# comment\n\nbody";
+        let cleaned = CleanedMessage::new(raw, "#");
+        assert_eq!(cleaned.message, "fature: typo\n\nbody");
+        // 'fature' starts at the beginning of the second original line
+        assert_eq!(cleaned.original_span(0..6), 18..24);
+        // 'body' comes after the skipped comment line
+        assert_eq!(cleaned.original_span(14..18), 42..46);
+        // Empty spans stay empty
+        assert_eq!(cleaned.original_span(12..12), 30..30);
+    }
+}
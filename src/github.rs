@@ -0,0 +1,153 @@
+// GitHub Actions integration: workflow command annotations, job summary and step outputs
+
+use crate::parser::ParseError;
+use crate::report::{get_error_details, line_column};
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::Path;
+
+/// Heredoc delimiter for multiline values in `$GITHUB_OUTPUT`, shared with the JS action
+const OUTPUT_DELIMITER: &str = "__CONVENTIONAL_PRS__";
+
+/// Escape the message of a workflow command, like `@actions/core` does
+fn escape_data(text: &str) -> String {
+    text.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a workflow command property value
+fn escape_property(text: &str) -> String {
+    escape_data(text).replace(':', "%3A").replace(',', "%2C")
+}
+
+/// One `::error` workflow command per error, or `::warning` for warnings. Lines and columns
+/// are 1-based and count characters; `endColumn` is inclusive. `file` is set for errors in a file on disk.
+pub fn annotations(file: Option<&str>, input: &str, errors: &[ParseError]) -> String {
+    let mut output = String::new();
+    for error in errors {
+        let (message, label, help) = get_error_details(&error.kind);
+        let (line, col) = line_column(input, error.span.start);
+        let (end_line, end_col) = line_column(input, error.span.end);
+        // Insertion points have an empty span; annotate the column they point at
+        let end_col = if error.span.is_empty() || end_col == 1 {
+            end_col
+        } else {
+            end_col - 1
+        };
+
+        let command = if error.is_warning() {
+            "warning"
+        } else {
+            "error"
+        };
+        let mut properties = Vec::new();
+        if let Some(file) = file {
+            properties.push(format!("file={}", escape_property(file)));
+        }
+        properties.push(format!(
+            "title={}",
+            escape_property(&format!("{message} ({})", error.kind.code()))
+        ));
+        properties.push(format!("line={line}"));
+        properties.push(format!("endLine={end_line}"));
+        properties.push(format!("col={col}"));
+        properties.push(format!("endColumn={end_col}"));
+
+        let data = match help {
+            Some(help) => format!("{label}\n{help}"),
+            None => label,
+        };
+        output.push_str(&format!(
+            "::{command} {}::{}\n",
+            properties.join(","),
+            escape_data(&data)
+        ));
+    }
+    output
+}
+
+fn append_to(path: &Path, content: &str) -> io::Result<()> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?
+        .write_all(content.as_bytes())
+}
+
+/// Append a (possibly multiline) step output to the file named by `$GITHUB_OUTPUT`.
+/// Does nothing outside of GitHub Actions.
+pub fn set_output(name: &str, value: &str) -> io::Result<()> {
+    match std::env::var_os("GITHUB_OUTPUT") {
+        Some(path) => append_to(
+            Path::new(&path),
+            &format!("{name}<<{OUTPUT_DELIMITER}\n{value}\n{OUTPUT_DELIMITER}\n"),
+        ),
+        None => Ok(()),
+    }
+}
+
+/// Append Markdown to the job summary named by `$GITHUB_STEP_SUMMARY`.
+/// Does nothing outside of GitHub Actions.
+pub fn append_step_summary(markdown: &str) -> io::Result<()> {
+    match std::env::var_os("GITHUB_STEP_SUMMARY") {
+        Some(path) => append_to(Path::new(&path), &format!("{markdown}\n")),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ConventionalParser;
+
+    fn errors(input: &str) -> Vec<ParseError> {
+        ConventionalParser::new(vec!["feat".to_string(), "fix".to_string()], None)
+            .parse_message(input)
+            .errors()
+            .map(<[ParseError]>::to_vec)
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_annotation_for_invalid_type() {
+        let input = "feta: add api";
+        let output = annotations(None, input, &errors(input));
+        assert_eq!(
+            output,
+            "::error title=Invalid commit type 'feta' (CP0001),line=1,endLine=1,col=1,endColumn=4::'feta' is not a valid type%0ADid you mean 'feat'?%0AValid types: feat, fix\n"
+        );
+    }
+
+    #[test]
+    fn test_annotation_with_file_and_second_line() {
+        let input = "feat: a\nbody";
+        let output = annotations(Some("a,b:c"), input, &errors(input));
+        assert!(output.starts_with("::error file=a%2Cb%3Ac,title=Missing blank line"));
+        assert!(output.contains("line=2,endLine=2,col=1,endColumn=4::"));
+    }
+
+    #[test]
+    fn test_insertion_point_annotates_single_column() {
+        let error = ParseError::new(crate::parser::ParseErrorKind::MissingDescription, 6..6);
+        let output = annotations(None, "feat: ", &[error]);
+        assert!(output.contains("col=7,endColumn=7"), "{output}");
+    }
+
+    #[test]
+    fn test_warning_annotation() {
+        let mut warning = ParseError::new(crate::parser::ParseErrorKind::TrailingSpaces, 7..9);
+        warning.severity = crate::config::Severity::Warn;
+        let output = annotations(None, "feat: a  ", &[warning]);
+        assert!(
+            output.starts_with("::warning title=Trailing spaces"),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn test_escaping() {
+        assert_eq!(escape_data("50% a\r\nb"), "50%25 a%0D%0Ab");
+        assert_eq!(escape_property("a:b,c"), "a%3Ab%2Cc");
+    }
+}
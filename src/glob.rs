@@ -0,0 +1,149 @@
+// Glob patterns for scopes: `*` and `?` wildcards and `{a,b}` alternatives
+
+/// Whether `scope` uses any pattern syntax
+pub fn is_pattern(scope: &str) -> bool {
+    scope.contains(['*', '?', '{'])
+}
+
+/// Index of the `}` closing the `{` at `open`, if any
+fn closing_brace(chars: &[char], open: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (idx, &c) in chars.iter().enumerate().skip(open) {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(idx);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Split the inside of a brace group at its top-level commas
+fn alternatives(chars: &[char]) -> Vec<String> {
+    let mut parts = vec![String::new()];
+    let mut depth = 0;
+    for &c in chars {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(String::new());
+                continue;
+            }
+            _ => {}
+        }
+        parts.last_mut().unwrap().push(c);
+    }
+    parts
+}
+
+/// Expand `{a,b}` alternatives, including nested ones: `{web,mobile}-ui` -> `web-ui`,
+/// `mobile-ui`. Unbalanced braces are kept as literal characters.
+pub fn expand_braces(pattern: &str) -> Vec<String> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let group = chars
+        .iter()
+        .enumerate()
+        .filter(|&(_, &c)| c == '{')
+        .find_map(|(open, _)| Some((open, closing_brace(&chars, open)?)));
+    let Some((open, close)) = group else {
+        return vec![pattern.to_string()];
+    };
+
+    let prefix: String = chars[..open].iter().collect();
+    let suffix: String = chars[close + 1..].iter().collect();
+    alternatives(&chars[open + 1..close])
+        .into_iter()
+        .flat_map(|alternative| expand_braces(&format!("{prefix}{alternative}{suffix}")))
+        .collect()
+}
+
+/// Match `text` against a pattern without braces, where `*` matches any run of
+/// characters and `?` exactly one
+fn matches_wildcards(pattern: &[char], text: &[char]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some(('*', rest)) => (0..=text.len()).any(|skip| matches_wildcards(rest, &text[skip..])),
+        Some((&p, rest)) => text
+            .split_first()
+            .is_some_and(|(&t, text)| (p == '?' || p == t) && matches_wildcards(rest, text)),
+    }
+}
+
+/// Whether `text` matches `pattern`; patterns without wildcards or braces match only
+/// themselves
+pub fn matches(pattern: &str, text: &str) -> bool {
+    if !is_pattern(pattern) {
+        return pattern == text;
+    }
+    let text: Vec<char> = text.chars().collect();
+    expand_braces(pattern).iter().any(|expanded| {
+        let expanded: Vec<char> = expanded.chars().collect();
+        matches_wildcards(&expanded, &text)
+    })
+}
+
+/// Whether `text` matches any of `patterns`
+pub fn matches_any(patterns: &[String], text: &str) -> bool {
+    patterns.iter().any(|pattern| matches(pattern, text))
+}
+
+/// Every literal value the patterns stand for: brace expansions without wildcards.
+/// Used as candidates for "did you mean" suggestions.
+pub fn literals(patterns: &[String]) -> Vec<String> {
+    patterns
+        .iter()
+        .flat_map(|pattern| expand_braces(pattern))
+        .filter(|literal| !literal.contains(['*', '?']))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_braces() {
+        assert_eq!(
+            expand_braces("{web,mobile}-ui"),
+            vec!["web-ui", "mobile-ui"]
+        );
+        assert_eq!(
+            expand_braces("{api,db}/{v1,v2}"),
+            vec!["api/v1", "api/v2", "db/v1", "db/v2"]
+        );
+        assert_eq!(expand_braces("a{b,{c,d}}"), vec!["ab", "ac", "ad"]);
+        assert_eq!(expand_braces("core"), vec!["core"]);
+        assert_eq!(expand_braces("{web"), vec!["{web"]);
+    }
+
+    #[test]
+    fn test_matches() {
+        assert!(matches("pkg-*", "pkg-core"));
+        assert!(matches("pkg-*", "pkg-"));
+        assert!(!matches("pkg-*", "pkgs-core"));
+        assert!(matches("api/v*", "api/v2"));
+        assert!(matches("api/v?", "api/v2"));
+        assert!(!matches("api/v?", "api/v10"));
+        assert!(matches("{web,mobile}-ui", "mobile-ui"));
+        assert!(!matches("{web,mobile}-ui", "desktop-ui"));
+        assert!(matches("*-{ui,api}", "web-api"));
+        assert!(matches("core", "core"));
+        assert!(!matches("core", "Core"));
+    }
+
+    #[test]
+    fn test_literals() {
+        let patterns = vec![
+            "pkg-*".to_string(),
+            "{web,mobile}-ui".to_string(),
+            "docs".to_string(),
+        ];
+        assert_eq!(literals(&patterns), vec!["web-ui", "mobile-ui", "docs"]);
+    }
+}
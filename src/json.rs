@@ -0,0 +1,451 @@
+// Versioned JSON output shared by the CLI (`--format json`) and the WASM bindings
+
+use crate::fix::{Applicability, Suggestion};
+use crate::parser::{CommitHeader, CommitMessage, ParseError, ParseErrorKind, ParseResult};
+use crate::report::{get_error_details, similar_candidates};
+use crate::similar::Candidate;
+use crate::trailer::Trailer;
+use serde::Serialize;
+
+/// Schema version of the JSON documents. Bumped on incompatible changes only;
+/// new optional fields may be added without a bump.
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+/// A half-open range `[start, end)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct JsonSpan {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl JsonSpan {
+    fn chars(span: &std::ops::Range<usize>) -> Self {
+        Self {
+            start: span.start,
+            end: span.end,
+        }
+    }
+
+    /// Convert a character span into a byte span of `input`
+    fn bytes(input: &str, span: &std::ops::Range<usize>) -> Self {
+        let byte_offset = |char_idx: usize| {
+            input
+                .char_indices()
+                .nth(char_idx)
+                .map_or(input.len(), |(idx, _)| idx)
+        };
+        Self {
+            start: byte_offset(span.start),
+            end: byte_offset(span.end),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct JsonHeader {
+    #[serde(rename = "type")]
+    pub commit_type: String,
+    pub scope: Option<Vec<String>>,
+    pub breaking: bool,
+    pub description: String,
+}
+
+impl From<&CommitHeader> for JsonHeader {
+    fn from(header: &CommitHeader) -> Self {
+        Self {
+            commit_type: header.commit_type.clone(),
+            scope: header.scope.clone(),
+            breaking: header.breaking,
+            description: header.description.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct JsonTrailer {
+    pub token: String,
+    pub separator: char,
+    pub value: String,
+    pub span: JsonSpan,
+}
+
+impl From<&Trailer> for JsonTrailer {
+    fn from(trailer: &Trailer) -> Self {
+        Self {
+            token: trailer.token.clone(),
+            separator: trailer.separator,
+            value: trailer.value.clone(),
+            span: JsonSpan::chars(&trailer.span),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonSuggestion {
+    pub span: JsonSpan,
+    pub byte_span: JsonSpan,
+    pub replacement: String,
+    /// `machine-applicable`, `maybe-incorrect` or `has-placeholders`
+    pub applicability: &'static str,
+}
+
+impl JsonSuggestion {
+    fn new(input: &str, suggestion: &Suggestion) -> Self {
+        Self {
+            span: JsonSpan::chars(&suggestion.span),
+            byte_span: JsonSpan::bytes(input, &suggestion.span),
+            replacement: suggestion.replacement.clone(),
+            applicability: match suggestion.applicability {
+                Applicability::MachineApplicable => "machine-applicable",
+                Applicability::MaybeIncorrect => "maybe-incorrect",
+                Applicability::HasPlaceholders => "has-placeholders",
+            },
+        }
+    }
+}
+
+/// A "did you mean" candidate with its confidence, from 0 to 1
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct JsonCandidate {
+    pub value: String,
+    pub confidence: f64,
+}
+
+impl From<Candidate> for JsonCandidate {
+    fn from(candidate: Candidate) -> Self {
+        Self {
+            value: candidate.value,
+            // Two decimals are plenty for ranking and keep the output stable
+            confidence: (candidate.confidence * 100.0).round() / 100.0,
+        }
+    }
+}
+
+/// A single error. `span` counts characters, `byteSpan` counts UTF-8 bytes.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonError {
+    /// Stable error code, see [`ParseErrorKind::code`]
+    pub code: String,
+    /// Stable kind name, see [`ParseErrorKind::name`]
+    pub kind: String,
+    /// `error` or `warning`
+    pub severity: &'static str,
+    pub message: String,
+    pub label: String,
+    pub help: Option<String>,
+    pub span: JsonSpan,
+    pub byte_span: JsonSpan,
+    /// The offending text, for kinds that carry it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub found: Option<String>,
+    /// The accepted values, for invalid types and scopes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<Vec<String>>,
+    /// Close matches among `expected`, best first
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub candidates: Vec<JsonCandidate>,
+    pub suggestion: Option<JsonSuggestion>,
+}
+
+impl JsonError {
+    pub fn new(input: &str, error: &ParseError) -> Self {
+        let (message, label, help) = get_error_details(&error.kind);
+        let (found, expected) = match &error.kind {
+            ParseErrorKind::InvalidType {
+                found, expected, ..
+            }
+            | ParseErrorKind::InvalidScope { found, expected }
+            | ParseErrorKind::ScopeNotAllowedForType {
+                found, expected, ..
+            }
+            | ParseErrorKind::TypeUsedAsScope {
+                found,
+                expected_scopes: expected,
+                ..
+            } => (Some(found.clone()), Some(expected.clone())),
+            ParseErrorKind::TypeAlias { found, canonical }
+            | ParseErrorKind::ScopeAlias { found, canonical }
+            | ParseErrorKind::TypeCase {
+                found,
+                expected: canonical,
+            } => (Some(found.clone()), Some(vec![canonical.clone()])),
+            ParseErrorKind::DeprecatedType {
+                found, replacement, ..
+            }
+            | ParseErrorKind::DeprecatedScope {
+                found, replacement, ..
+            } => (Some(found.clone()), replacement.clone().map(|r| vec![r])),
+            ParseErrorKind::UnexpectedChar(c) => (Some(c.to_string()), None),
+            ParseErrorKind::TrailerTokenWithSpaces { token } => (Some(token.clone()), None),
+            ParseErrorKind::DescriptionNotImperative { found, .. } => (Some(found.clone()), None),
+            _ => (None, None),
+        };
+
+        Self {
+            code: error.kind.code().to_string(),
+            kind: error.kind.name().to_string(),
+            severity: if error.is_warning() {
+                "warning"
+            } else {
+                "error"
+            },
+            message,
+            label,
+            help,
+            span: JsonSpan::chars(&error.span),
+            byte_span: JsonSpan::bytes(input, &error.span),
+            found,
+            expected,
+            candidates: similar_candidates(&error.kind)
+                .into_iter()
+                .map(JsonCandidate::from)
+                .collect(),
+            suggestion: error
+                .suggestion
+                .as_ref()
+                .map(|suggestion| JsonSuggestion::new(input, suggestion)),
+        }
+    }
+}
+
+/// Outcome for one validated input (a title, a commit message, ...)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct JsonResult {
+    /// Where the input came from, e.g. `title` or a commit SHA, in batch documents
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub ok: bool,
+    pub input: String,
+    /// The parsed header, present when the input is valid
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<JsonHeader>,
+    /// The message body, present for valid full messages that have one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub trailers: Vec<JsonTrailer>,
+    pub errors: Vec<JsonError>,
+    /// Diagnostics for rules configured as `warn`; they don't affect `ok`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<JsonError>,
+}
+
+impl JsonResult {
+    /// Result for an input that failed to parse. Spans in `errors` index into `input`.
+    pub fn from_errors(input: &str, errors: &[ParseError]) -> Self {
+        Self {
+            name: None,
+            ok: errors.is_empty(),
+            input: input.to_string(),
+            header: None,
+            body: None,
+            trailers: Vec::new(),
+            errors: errors
+                .iter()
+                .map(|error| JsonError::new(input, error))
+                .collect(),
+            warnings: Vec::new(),
+        }
+    }
+
+    pub fn from_header(input: &str, header: &CommitHeader) -> Self {
+        Self {
+            header: Some(header.into()),
+            ..Self::from_errors(input, &[])
+        }
+    }
+
+    pub fn from_message(input: &str, message: &CommitMessage) -> Self {
+        Self {
+            body: message.body.clone(),
+            trailers: message.trailers.iter().map(JsonTrailer::from).collect(),
+            ..Self::from_header(input, &message.header)
+        }
+    }
+
+    /// Attach warnings. Spans index into `input`.
+    pub fn with_warnings(mut self, input: &str, warnings: &[ParseError]) -> Self {
+        self.warnings = warnings
+            .iter()
+            .map(|warning| JsonError::new(input, warning))
+            .collect();
+        self
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+}
+
+/// JSON document for a single input
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct JsonReport {
+    pub version: u32,
+    #[serde(flatten)]
+    pub result: JsonResult,
+}
+
+impl JsonReport {
+    pub fn new(result: JsonResult) -> Self {
+        Self {
+            version: JSON_SCHEMA_VERSION,
+            result,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("JSON report serialization cannot fail")
+    }
+}
+
+/// JSON document for several inputs, e.g. a pull request or a commit range.
+/// `ok` is the overall outcome, which need not require every result to be ok.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct JsonBatchReport {
+    pub version: u32,
+    pub ok: bool,
+    pub results: Vec<JsonResult>,
+}
+
+impl JsonBatchReport {
+    pub fn new(ok: bool, results: Vec<JsonResult>) -> Self {
+        Self {
+            version: JSON_SCHEMA_VERSION,
+            ok,
+            results,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("JSON report serialization cannot fail")
+    }
+}
+
+impl ParseResult<'_, CommitHeader> {
+    /// Build the JSON document for a parsed header
+    pub fn to_json_report(&self) -> JsonReport {
+        JsonReport::new(
+            match (self.output(), self.errors()) {
+                (Some(header), _) => JsonResult::from_header(self.input(), header),
+                (None, errors) => JsonResult::from_errors(self.input(), errors.unwrap_or_default()),
+            }
+            .with_warnings(self.input(), self.warnings()),
+        )
+    }
+}
+
+impl ParseResult<'_, CommitMessage> {
+    /// Build the JSON document for a parsed commit message
+    pub fn to_json_report(&self) -> JsonReport {
+        JsonReport::new(
+            match (self.output(), self.errors()) {
+                (Some(message), _) => JsonResult::from_message(self.input(), message),
+                (None, errors) => JsonResult::from_errors(self.input(), errors.unwrap_or_default()),
+            }
+            .with_warnings(self.input(), self.warnings()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ConventionalParser;
+    use serde_json::Value;
+
+    fn parser() -> ConventionalParser {
+        ConventionalParser::new(
+            vec!["feat".to_string(), "fix".to_string()],
+            Some(vec!["api".to_string()]),
+        )
+    }
+
+    fn to_value(report: &JsonReport) -> Value {
+        serde_json::from_str(&report.to_json()).unwrap()
+    }
+
+    #[test]
+    fn test_valid_header_document() {
+        let json = to_value(&parser().parse("feat(api)!: add endpoint").to_json_report());
+        assert_eq!(json["version"], JSON_SCHEMA_VERSION);
+        assert_eq!(json["ok"], true);
+        assert_eq!(json["header"]["type"], "feat");
+        assert_eq!(json["header"]["scope"][0], "api");
+        assert_eq!(json["header"]["breaking"], true);
+        assert_eq!(json["errors"].as_array().unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_error_document() {
+        let json = to_value(&parser().parse("feta: add endpoint").to_json_report());
+        assert_eq!(json["ok"], false);
+        assert!(json.get("header").is_none());
+
+        let error = &json["errors"][0];
+        assert_eq!(error["code"], "CP0001");
+        assert_eq!(error["kind"], "invalid-type");
+        assert_eq!(error["message"], "Invalid commit type 'feta'");
+        assert_eq!(error["label"], "'feta' is not a valid type");
+        assert_eq!(error["found"], "feta");
+        assert_eq!(error["expected"][1], "fix");
+        assert_eq!(error["span"]["start"], 0);
+        assert_eq!(error["span"]["end"], 4);
+        assert_eq!(error["suggestion"]["replacement"], "feat");
+        assert_eq!(error["suggestion"]["applicability"], "maybe-incorrect");
+        assert_eq!(error["candidates"][0]["value"], "feat");
+        assert_eq!(error["candidates"][0]["confidence"], 0.88);
+        assert_eq!(error["candidates"].as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_byte_spans_differ_from_char_spans_after_multibyte_text() {
+        let json = to_value(&parser().parse("feat(api): café  ").to_json_report());
+        let error = &json["errors"][0];
+        assert_eq!(error["kind"], "trailing-spaces");
+        assert_eq!(error["span"]["start"], 15);
+        assert_eq!(error["byteSpan"]["start"], 16);
+        assert_eq!(error["byteSpan"]["end"], 18);
+    }
+
+    #[test]
+    fn test_message_document_includes_body_and_trailers() {
+        let input = "feat: add api\n\nLonger text.\n\nRefs: #1";
+        let json = to_value(&parser().parse_message(input).to_json_report());
+        assert_eq!(json["ok"], true);
+        assert_eq!(json["body"], "Longer text.");
+        assert_eq!(json["trailers"][0]["token"], "Refs");
+        assert_eq!(json["trailers"][0]["value"], "#1");
+    }
+
+    #[test]
+    fn test_warnings_do_not_affect_ok() {
+        let mut rules = std::collections::BTreeMap::new();
+        rules.insert("trailing-spaces".to_string(), crate::config::Severity::Warn);
+        let json = to_value(
+            &parser()
+                .with_rules(rules)
+                .parse("feat(api): add  ")
+                .to_json_report(),
+        );
+        assert_eq!(json["ok"], true);
+        assert_eq!(json["header"]["description"], "add");
+        assert_eq!(json["errors"].as_array().unwrap().len(), 0);
+        assert_eq!(json["warnings"][0]["kind"], "trailing-spaces");
+        assert_eq!(json["warnings"][0]["severity"], "warning");
+    }
+
+    #[test]
+    fn test_batch_document() {
+        let results = vec![
+            JsonResult::from_errors("feat: a", &[]).with_name("title"),
+            JsonResult::from_errors("fix: b", &[]).with_name("abc1234"),
+        ];
+        let json: Value =
+            serde_json::from_str(&JsonBatchReport::new(true, results).to_json()).unwrap();
+        assert_eq!(json["version"], JSON_SCHEMA_VERSION);
+        assert_eq!(json["results"][1]["name"], "abc1234");
+    }
+}
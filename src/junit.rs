@@ -0,0 +1,174 @@
+// JUnit XML output so CI systems show commit message checks as test results
+
+use crate::config::CharSetConfig;
+use crate::parser::ParseError;
+use crate::report::{ErrorReporter, OutputFormat, get_error_details};
+
+/// A validated commit message or title, reported as one `<testcase>`
+#[derive(Debug, Clone, PartialEq)]
+pub struct JunitCase<'a> {
+    /// Where the input came from, e.g. `title` or a commit SHA
+    pub name: String,
+    pub input: &'a str,
+    /// Errors and warnings
+    pub errors: Vec<ParseError>,
+}
+
+impl<'a> JunitCase<'a> {
+    pub fn new(name: impl Into<String>, input: &'a str, errors: Vec<ParseError>) -> Self {
+        Self {
+            name: name.into(),
+            input,
+            errors,
+        }
+    }
+
+    /// Warnings alone don't fail a test case
+    fn is_failure(&self) -> bool {
+        self.errors.iter().any(|error| !error.is_warning())
+    }
+}
+
+/// Escape text for use in XML element content
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            // Control characters other than tab and newline are not allowed in XML 1.0
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Escape text for use in a double-quoted XML attribute
+fn escape_attr(text: &str) -> String {
+    escape(text).replace('"', "&quot;")
+}
+
+/// Render a JUnit XML report with one `<testsuite>` named `suite` and one `<testcase>`
+/// per case. Failures contain the ASCII `ErrorReporter` rendering of the errors; cases
+/// with only warnings pass and keep the rendering in `<system-out>`.
+pub fn junit_report(suite: &str, cases: &[JunitCase], charset: CharSetConfig) -> String {
+    let reporter = ErrorReporter::new(OutputFormat::Ascii, charset);
+    let failures = cases.iter().filter(|case| case.is_failure()).count();
+    let tests = cases.len();
+    let suite = escape_attr(suite);
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<testsuites name=\"{}\" tests=\"{tests}\" failures=\"{failures}\" errors=\"0\">\n",
+        env!("CARGO_PKG_NAME")
+    ));
+    xml.push_str(&format!(
+        "  <testsuite name=\"{suite}\" tests=\"{tests}\" failures=\"{failures}\" errors=\"0\" skipped=\"0\">\n"
+    ));
+
+    for case in cases {
+        let header = case.input.lines().next().unwrap_or_default();
+        let name = escape_attr(&format!("{}: {header}", case.name));
+        if case.errors.is_empty() {
+            xml.push_str(&format!(
+                "    <testcase classname=\"{suite}\" name=\"{name}\"/>\n"
+            ));
+            continue;
+        }
+
+        let body = reporter.report_errors_named(&case.name, case.input, &case.errors);
+        xml.push_str(&format!(
+            "    <testcase classname=\"{suite}\" name=\"{name}\">\n"
+        ));
+
+        let failing: Vec<&ParseError> = case
+            .errors
+            .iter()
+            .filter(|error| !error.is_warning())
+            .collect();
+        match failing.first() {
+            Some(first) => {
+                let (message, _, _) = get_error_details(&first.kind);
+                let message = match failing.len() {
+                    1 => message,
+                    count => format!("{message} (and {} more)", count - 1),
+                };
+                xml.push_str(&format!(
+                    "      <failure message=\"{}\" type=\"{}\">{}</failure>\n",
+                    escape_attr(&message),
+                    first.kind.code(),
+                    escape(&body)
+                ));
+            }
+            // Passing test case with warnings: keep the report as test output
+            None => xml.push_str(&format!(
+                "      <system-out>{}</system-out>\n",
+                escape(&body)
+            )),
+        }
+        xml.push_str("    </testcase>\n");
+    }
+
+    xml.push_str("  </testsuite>\n</testsuites>\n");
+    xml
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::ConventionalParser;
+
+    fn case<'a>(name: &str, input: &'a str) -> JunitCase<'a> {
+        let errors = ConventionalParser::new(vec!["feat".to_string(), "fix".to_string()], None)
+            .parse_message(input)
+            .errors()
+            .map(<[ParseError]>::to_vec)
+            .unwrap_or_default();
+        JunitCase::new(name, input, errors)
+    }
+
+    #[test]
+    fn test_one_testcase_per_input() {
+        let xml = junit_report(
+            "commits",
+            &[case("abc1234", "feat: a"), case("def5678", "feta: <b>")],
+            CharSetConfig::Ascii,
+        );
+
+        assert!(xml.contains("<testsuite name=\"commits\" tests=\"2\" failures=\"1\""));
+        assert!(xml.contains("<testcase classname=\"commits\" name=\"abc1234: feat: a\"/>"));
+        assert!(xml.contains("name=\"def5678: feta: &lt;b&gt;\""));
+        assert!(xml.contains("<failure message=\"Invalid commit type 'feta'\" type=\"CP0001\">"));
+        assert_eq!(xml.matches("<testcase").count(), 2);
+    }
+
+    #[test]
+    fn test_failure_body_is_ascii_report() {
+        let xml = junit_report("input", &[case("input", "feta: a")], CharSetConfig::Ascii);
+        assert!(xml.contains("[ input:1:1 ]"));
+        assert!(!xml.contains('\u{1b}'));
+    }
+
+    #[test]
+    fn test_warnings_do_not_fail() {
+        let mut warning = ParseError::new(crate::parser::ParseErrorKind::TrailingSpaces, 7..9);
+        warning.severity = crate::config::Severity::Warn;
+        let xml = junit_report(
+            "input",
+            &[JunitCase::new("input", "feat: a  ", vec![warning])],
+            CharSetConfig::Ascii,
+        );
+        assert!(xml.contains("failures=\"0\""));
+        assert!(xml.contains("<system-out>[CP0015] Warning: Trailing spaces"));
+        assert!(!xml.contains("<failure"));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("a & \"b\" <c>\u{7}"), "a &amp; \"b\" &lt;c&gt;");
+        assert_eq!(escape_attr("\"b\""), "&quot;b&quot;");
+    }
+}
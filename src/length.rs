@@ -0,0 +1,112 @@
+// Text length in display columns or grapheme clusters, as used by the length rules
+
+use crate::config::LengthUnit;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+fn grapheme_length(grapheme: &str, unit: LengthUnit) -> usize {
+    match unit {
+        // Measured per cluster, so emoji sequences count as their rendered width
+        LengthUnit::Width => grapheme.width(),
+        LengthUnit::Graphemes => 1,
+    }
+}
+
+/// Length of `text` in `unit`
+pub fn measure(text: &str, unit: LengthUnit) -> usize {
+    text.graphemes(true)
+        .map(|grapheme| grapheme_length(grapheme, unit))
+        .sum()
+}
+
+/// Number of characters in the longest prefix of `text` that is at most `max` long.
+/// Grapheme clusters are never split.
+pub(crate) fn fitting_chars(text: &str, max: usize, unit: LengthUnit) -> usize {
+    let mut length = 0;
+    let mut chars = 0;
+    for grapheme in text.graphemes(true) {
+        length += grapheme_length(grapheme, unit);
+        if length > max {
+            break;
+        }
+        chars += grapheme.chars().count();
+    }
+    chars
+}
+
+/// Number of characters to keep so `text` is at most `max` long without splitting a
+/// word, or `None` if `text` already fits or its first word is too long
+pub(crate) fn truncate_at_word(text: &str, max: usize, unit: LengthUnit) -> Option<usize> {
+    let chars: Vec<char> = text.chars().collect();
+    let fits = fitting_chars(text, max, unit);
+    if fits == chars.len() {
+        return None;
+    }
+    let mut keep = if chars[fits].is_whitespace() {
+        fits
+    } else {
+        chars[..fits].iter().rposition(|c| c.is_whitespace())?
+    };
+    while keep > 0 && chars[keep - 1].is_whitespace() {
+        keep -= 1;
+    }
+    (keep > 0).then_some(keep)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_width_counts_wide_characters_twice() {
+        assert_eq!(measure("add login", LengthUnit::Width), 9);
+        assert_eq!(measure("로그인 추가", LengthUnit::Width), 11);
+        assert_eq!(measure("ログイン", LengthUnit::Width), 8);
+        assert_eq!(measure("café", LengthUnit::Width), 4);
+        assert_eq!(measure("cafe\u{301}", LengthUnit::Width), 4);
+    }
+
+    #[test]
+    fn test_emoji_sequences_count_as_rendered() {
+        let family = "👨\u{200d}👩\u{200d}👧";
+        assert_eq!(measure(family, LengthUnit::Width), 2);
+        assert_eq!(measure(family, LengthUnit::Graphemes), 1);
+        assert_eq!(measure("🇰🇷", LengthUnit::Width), 2);
+        assert_eq!(measure("👍🏽", LengthUnit::Graphemes), 1);
+    }
+
+    #[test]
+    fn test_graphemes() {
+        assert_eq!(measure("로그인 추가", LengthUnit::Graphemes), 6);
+        assert_eq!(measure("cafe\u{301}", LengthUnit::Graphemes), 4);
+    }
+
+    #[test]
+    fn test_fitting_chars_keeps_clusters_whole() {
+        assert_eq!(fitting_chars("로그인", 3, LengthUnit::Width), 1);
+        assert_eq!(fitting_chars("로그인", 4, LengthUnit::Width), 2);
+        assert_eq!(fitting_chars("cafe\u{301}s", 4, LengthUnit::Graphemes), 5);
+        assert_eq!(fitting_chars("abc", 5, LengthUnit::Width), 3);
+    }
+
+    #[test]
+    fn test_truncate_at_word() {
+        assert_eq!(
+            truncate_at_word("add new login", 9, LengthUnit::Width),
+            Some(7)
+        );
+        assert_eq!(
+            truncate_at_word("add new login", 7, LengthUnit::Width),
+            Some(7)
+        );
+        assert_eq!(truncate_at_word("add new", 7, LengthUnit::Width), None);
+        assert_eq!(
+            truncate_at_word("authentication", 4, LengthUnit::Width),
+            None
+        );
+        assert_eq!(
+            truncate_at_word("로그인 추가", 8, LengthUnit::Width),
+            Some(3)
+        );
+    }
+}
@@ -1,7 +1,37 @@
 pub mod config;
+pub mod explain;
+pub mod fix;
+pub mod git;
+pub mod github;
+pub mod glob;
+pub mod json;
+pub mod junit;
+pub mod length;
+pub mod lint;
 pub mod parser;
+pub mod pull_request;
 pub mod report;
+pub mod sarif;
+pub mod similar;
+pub mod style;
+pub mod trailer;
+pub mod wording;
 
-pub use config::{CharSetConfig, Config, ConfigFormat};
-pub use parser::{CommitHeader, ConventionalParser, ParseError, ParseErrorKind, ParseResult};
+pub use config::{
+    CharSetConfig, Config, ConfigError, ConfigFormat, DeprecatedConfig, Deprecation,
+    DescriptionCase, DescriptionConfig, ParserMode, ScopePolicy, Severity, TypeScopes,
+};
+pub use explain::Explanation;
+pub use fix::{Applicability, Suggestion};
+pub use json::{JsonBatchReport, JsonReport, JsonResult};
+pub use lint::{Linter, Rule, RuleContext};
+pub use parser::{
+    CommitHeader, CommitMessage, ConventionalParser, ParseError, ParseErrorKind, ParseResult,
+};
+pub use pull_request::{
+    MessageCheck, PullRequestValidation, ValidationMode, check_message, validate_pull_request,
+};
 pub use report::{ErrorReporter, OutputFormat};
+#[allow(deprecated)]
+pub use trailer::Footer;
+pub use trailer::Trailer;
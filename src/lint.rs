@@ -0,0 +1,337 @@
+// Pluggable lint rules for parsed commit messages, and the Linter that runs them after the parser
+
+use crate::config::{Config, Severity};
+use crate::fix;
+use crate::parser::{CommitMessage, ConventionalParser, ParseError, ParseResult};
+use crate::style;
+use std::ops::Range;
+
+/// A parsed commit message and the text it was parsed from. Spans are character offsets
+/// into `input`, like those of every [`ParseError`].
+pub struct RuleContext<'a> {
+    pub input: &'a str,
+    pub message: &'a CommitMessage,
+}
+
+impl<'a> RuleContext<'a> {
+    /// The header line, without a trailing '\r'
+    pub fn header(&self) -> &'a str {
+        let line = self.input.split('\n').next().unwrap_or_default();
+        line.strip_suffix('\r').unwrap_or(line)
+    }
+
+    /// Span of the header line
+    pub fn header_span(&self) -> Range<usize> {
+        0..self.header().chars().count()
+    }
+
+    /// Span of the commit type as written, after any `fixup!`/`squash!` prefix. The parsed
+    /// type may differ from the text when it was written as an alias.
+    pub fn type_span(&self) -> Range<usize> {
+        let (header, offset) = ConventionalParser::strip_git_autosquash_prefixes(self.header());
+        let len = header
+            .chars()
+            .take_while(|c| c.is_alphanumeric() || *c == '-')
+            .count();
+        offset..offset + len
+    }
+
+    /// Span of the description, which ends the header apart from trailing whitespace
+    pub fn description_span(&self) -> Range<usize> {
+        let end = self.header().trim_end().chars().count();
+        end - self.message.header.description.chars().count()..end
+    }
+
+    /// Span of the body, when there is one and it appears verbatim in the input
+    pub fn body_span(&self) -> Option<Range<usize>> {
+        let body = self.message.body.as_deref()?;
+        // The body follows the header, which may contain the same text
+        let header_end = self.header().len();
+        let byte_start = header_end + self.input[header_end..].find(body)?;
+        let start = self.input[..byte_start].chars().count();
+        Some(start..start + body.chars().count())
+    }
+}
+
+/// A check on successfully parsed commit messages, e.g. a team's house rule.
+///
+/// Diagnostics are usually built with [`ParseError::custom`] and the spans of the
+/// [`RuleContext`]; attach a [`Suggestion`](crate::Suggestion) to make them fixable.
+/// They are errors unless [`Linter::with_rule_severity`] says otherwise; `Config::rules`
+/// only accepts built-in rules.
+pub trait Rule {
+    /// Stable kebab-case name, used as the kind name and code of its diagnostics
+    fn name(&self) -> &str;
+
+    fn check(&self, context: &RuleContext) -> Vec<ParseError>;
+}
+
+/// Parses commit messages and runs lint rules on the ones that parse, returning the
+/// parser's and the rules' diagnostics as one [`ParseResult`]
+pub struct Linter {
+    parser: ConventionalParser,
+    rules: Vec<Box<dyn Rule>>,
+}
+
+impl Linter {
+    pub fn new(parser: ConventionalParser) -> Self {
+        Self {
+            parser,
+            rules: Vec::new(),
+        }
+    }
+
+    /// Linter for the parser settings and built-in rules of a configuration
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            parser: ConventionalParser::from_config(config),
+            rules: style::rules_from_config(config),
+        }
+    }
+
+    /// Add a rule. Rules run in the order they were added.
+    pub fn with_rule(mut self, rule: impl Rule + 'static) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Set the severity of a rule by name, e.g. of a rule added with [`Linter::with_rule`]
+    pub fn with_rule_severity(mut self, rule: impl Into<String>, severity: Severity) -> Self {
+        self.parser = self.parser.with_rule_severity(rule, severity);
+        self
+    }
+
+    /// Evaluate deprecation dates as of `today` (`YYYY-MM-DD`) instead of the current date
+    pub fn with_today(mut self, today: impl Into<String>) -> Self {
+        self.parser = self.parser.with_today(today);
+        self
+    }
+
+    pub fn parser(&self) -> &ConventionalParser {
+        &self.parser
+    }
+
+    /// Parse a full commit message and, if it parses, run every rule on it
+    pub fn lint<'a>(&self, input: &'a str) -> ParseResult<'a, CommitMessage> {
+        let result = self.parser.parse_message(input);
+        let Some(message) = result.output() else {
+            return result;
+        };
+
+        let context = RuleContext { input, message };
+        let mut diagnostics: Vec<_> = self
+            .rules
+            .iter()
+            .flat_map(|rule| rule.check(&context))
+            .collect();
+        fix::attach_suggestions(input, &mut diagnostics);
+        let (errors, rule_warnings) = self.parser.apply_rules(diagnostics);
+
+        let mut warnings = result.warnings().to_vec();
+        warnings.extend(rule_warnings);
+        let result = if errors.is_empty() {
+            result.into_result()
+        } else {
+            Err(errors)
+        };
+        ParseResult::new(input, result).with_warnings(warnings)
+    }
+
+    /// Like [`ConventionalParser::fix_message`], including fixes suggested by rules
+    pub fn fix_message(&self, input: &str) -> String {
+        fix::fix_until_stable(input, |message| self.lint(message).diagnostics())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fix::Suggestion;
+    use crate::parser::ParseErrorKind;
+
+    /// `perf` commits must mention a benchmark in their body
+    struct PerfNeedsBenchmark;
+
+    impl Rule for PerfNeedsBenchmark {
+        fn name(&self) -> &str {
+            "perf-needs-benchmark"
+        }
+
+        fn check(&self, context: &RuleContext) -> Vec<ParseError> {
+            let header = &context.message.header;
+            let mentions_benchmark = context
+                .message
+                .body
+                .as_deref()
+                .is_some_and(|body| body.contains("benchmark"));
+            if header.commit_type != "perf" || mentions_benchmark {
+                return Vec::new();
+            }
+            vec![
+                ParseError::custom(
+                    self.name(),
+                    context.type_span(),
+                    "Performance change without a benchmark",
+                    "perf commits must mention a benchmark",
+                )
+                .with_help("Add benchmark results to the commit body"),
+            ]
+        }
+    }
+
+    /// Descriptions must not end with a period
+    struct NoPeriod;
+
+    impl Rule for NoPeriod {
+        fn name(&self) -> &str {
+            "no-period"
+        }
+
+        fn check(&self, context: &RuleContext) -> Vec<ParseError> {
+            let span = context.description_span();
+            if !context.message.header.description.ends_with('.') {
+                return Vec::new();
+            }
+            let period = span.end - 1..span.end;
+            vec![
+                ParseError::custom(
+                    self.name(),
+                    period.clone(),
+                    "Trailing period",
+                    "remove this",
+                )
+                .with_suggestion(Suggestion::remove(period)),
+            ]
+        }
+    }
+
+    fn linter() -> Linter {
+        Linter::new(ConventionalParser::new(
+            vec!["feat".to_string(), "perf".to_string()],
+            None,
+        ))
+        .with_rule(PerfNeedsBenchmark)
+        .with_rule(NoPeriod)
+    }
+
+    #[test]
+    fn test_rule_diagnostics_make_the_message_invalid() {
+        let linter = linter();
+        let result = linter.lint("fixup! perf: cache lookups");
+        let errors = result.errors().unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind.name(), "perf-needs-benchmark");
+        assert_eq!(errors[0].kind.code(), "perf-needs-benchmark");
+        assert_eq!(errors[0].span, 7..11);
+
+        assert!(
+            linter
+                .lint("perf: cache lookups\n\nSee the benchmark in #12.")
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_rules_only_run_on_parsed_messages() {
+        let errors = linter().lint("perf cache lookups").unwrap_err();
+        assert!(
+            errors
+                .iter()
+                .all(|error| !matches!(error.kind, ParseErrorKind::Custom { .. }))
+        );
+    }
+
+    #[test]
+    fn test_with_today_sets_the_deprecation_date() {
+        let config = Config {
+            deprecated: crate::config::DeprecatedConfig {
+                types: [(
+                    "feature".to_string(),
+                    crate::config::Deprecation {
+                        replacement: Some("feat".to_string()),
+                        until: Some("2026-06-30".to_string()),
+                    },
+                )]
+                .into(),
+                ..Default::default()
+            },
+            ..Config::default()
+        };
+        let before = Linter::from_config(&config).with_today("2026-06-30");
+        assert!(before.lint("feature: add login").is_ok());
+        let after = Linter::from_config(&config).with_today("2026-07-01");
+        assert!(after.lint("feature: add login").is_err());
+    }
+
+    #[test]
+    fn test_rule_severity_from_config_rules() {
+        let parser = ConventionalParser::new(vec!["perf".to_string()], None).with_rules(
+            [("perf-needs-benchmark".to_string(), Severity::Warn)]
+                .into_iter()
+                .collect(),
+        );
+        let result = Linter::new(parser)
+            .with_rule(PerfNeedsBenchmark)
+            .lint("perf: cache lookups");
+        assert!(result.is_ok());
+        assert_eq!(result.warnings()[0].kind.name(), "perf-needs-benchmark");
+    }
+
+    #[test]
+    fn test_custom_rule_severity_with_config() {
+        let config = Config {
+            types: vec!["perf".into()],
+            ..Config::default()
+        };
+        let warn = Linter::from_config(&config)
+            .with_rule(PerfNeedsBenchmark)
+            .with_rule_severity("perf-needs-benchmark", Severity::Warn);
+        let result = warn.lint("perf: cache lookups");
+        assert!(result.is_ok());
+        assert_eq!(result.warnings()[0].kind.name(), "perf-needs-benchmark");
+
+        let off = Linter::from_config(&config)
+            .with_rule(PerfNeedsBenchmark)
+            .with_rule_severity("perf-needs-benchmark", Severity::Off);
+        let result = off.lint("perf: cache lookups");
+        assert!(result.is_ok());
+        assert!(result.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_fix_message_applies_rule_suggestions() {
+        let fixed = linter().fix_message("feat: add api.  ");
+        assert_eq!(fixed, "feat: add api");
+    }
+
+    #[test]
+    fn test_context_spans() {
+        let input = "feat: add api\n\nBody text.";
+        let message = ConventionalParser::new(vec!["feat".to_string()], None)
+            .parse_message(input)
+            .unwrap();
+        let context = RuleContext {
+            input,
+            message: &message,
+        };
+        assert_eq!(context.header_span(), 0..13);
+        assert_eq!(context.type_span(), 0..4);
+        assert_eq!(context.description_span(), 6..13);
+        assert_eq!(context.body_span(), Some(15..25));
+    }
+
+    #[test]
+    fn test_body_span_skips_the_header() {
+        let input = "feat: add api
+
+api";
+        let message = ConventionalParser::new(vec!["feat".to_string()], None)
+            .parse_message(input)
+            .unwrap();
+        let context = RuleContext {
+            input,
+            message: &message,
+        };
+        assert_eq!(context.body_span(), Some(15..18));
+    }
+}
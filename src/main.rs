@@ -1,7 +1,17 @@
 use clap::Parser;
-use conventional_prs::{CharSetConfig, Config, ConventionalParser, OutputFormat};
+use conventional_prs::explain::{EXPLANATIONS, Explanation};
+use conventional_prs::git::{self, CleanedMessage, Commit};
+use conventional_prs::github;
+use conventional_prs::junit::{JunitCase, junit_report};
+use conventional_prs::sarif::{SarifArtifact, sarif_log};
+use conventional_prs::{
+    CharSetConfig, CommitMessage, Config, ConventionalParser, ErrorReporter, JsonBatchReport,
+    JsonReport, JsonResult, Linter, MessageCheck, OutputFormat, ParseError, ParseResult,
+    check_message, validate_pull_request,
+};
+use std::fs;
 use std::io::{self, Read};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process;
 
 #[derive(Parser, Debug)]
@@ -11,28 +21,93 @@ use std::process;
     version
 )]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// Path to configuration file
-    #[arg(long, value_name = "PATH")]
+    #[arg(long, value_name = "PATH", global = true)]
     config: Option<PathBuf>,
 
     /// Input string to validate (if not provided, reads from stdin)
     #[arg(long, value_name = "STRING")]
     input: Option<String>,
 
-    /// Output format (default or github)
-    #[arg(long, value_enum, default_value = "default")]
+    /// Commit message file to validate (e.g. .git/COMMIT_EDITMSG from a commit-msg hook).
+    /// Git comment lines and everything below the scissors line are ignored
+    #[arg(long, value_name = "PATH", conflicts_with = "input")]
+    commit_msg_file: Option<PathBuf>,
+
+    /// File of messages to validate, one per line. Entries separated by NUL bytes (as
+    /// printed by `git log -z --format=%B`) may span several lines
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["input", "commit_msg_file"])]
+    batch_file: Option<PathBuf>,
+
+    /// Print the input with suggested fixes applied instead of an error report
+    #[arg(long)]
+    fix: bool,
+
+    /// Output format (default, github, json, sarif, junit, markdown or compact). `github` also emits workflow
+    /// annotations, a job summary and step outputs when run in GitHub Actions
+    #[arg(long, value_enum, default_value = "default", global = true)]
     format: Format,
 
     /// Character set for error rendering (ascii or unicode)
-    #[arg(long, value_enum)]
+    #[arg(long, value_enum, global = true)]
     charset: Option<CharSet>,
+
+    /// Fail when there are more warnings than this (overrides `maxWarnings` in the config)
+    #[arg(long, value_name = "N", global = true)]
+    max_warnings: Option<usize>,
 }
 
-#[derive(Debug, Clone, clap::ValueEnum)]
+#[derive(clap::Subcommand, Debug)]
+enum Command {
+    /// Validate a pull request title and its commits using the semantic-prs modes
+    /// (titleOnly, commitsOnly, titleAndCommits, anyCommit) from the configuration
+    Pr {
+        /// Pull request title
+        #[arg(long, value_name = "STRING")]
+        title: String,
+
+        /// Commit message, repeated once per commit in the pull request
+        #[arg(long = "commit", value_name = "MESSAGE")]
+        commits: Vec<String>,
+    },
+    /// Validate commits from the local git repository
+    #[command(group = clap::ArgGroup::new("commits").required(true))]
+    Check {
+        /// Revision range to validate (e.g. origin/main..HEAD)
+        #[arg(long, value_name = "RANGE", group = "commits")]
+        range: Option<String>,
+
+        /// Validate the last N commits reachable from HEAD
+        #[arg(long, value_name = "N", group = "commits")]
+        last: Option<usize>,
+    },
+    /// Explain an error code with examples and the spec clause it enforces.
+    /// Lists every code when none is given
+    Explain {
+        /// Error code (e.g. CP0014) or kind name (e.g. missing-space)
+        #[arg(value_name = "CODE")]
+        code: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
 enum Format {
     Default,
     #[value(name = "github")]
     GitHub,
+    /// Versioned JSON document on stdout
+    Json,
+    /// SARIF 2.1.0 log on stdout, for code-scanning dashboards
+    Sarif,
+    /// JUnit XML on stdout, one test case per title or commit
+    Junit,
+    /// Markdown report on stdout, e.g. for a PR comment
+    Markdown,
+    /// One `input:line:column: error[kind] (code): message` line per error
+    Compact,
 }
 
 #[derive(Debug, Clone, clap::ValueEnum)]
@@ -45,7 +120,10 @@ impl From<Format> for OutputFormat {
     fn from(f: Format) -> Self {
         match f {
             Format::Default => OutputFormat::Color,
-            Format::GitHub => OutputFormat::Ascii,
+            Format::Markdown => OutputFormat::Markdown,
+            Format::Compact => OutputFormat::Compact,
+            // Machine formats fall back to plain text for any human-facing output
+            Format::GitHub | Format::Json | Format::Sarif | Format::Junit => OutputFormat::Ascii,
         }
     }
 }
@@ -62,6 +140,10 @@ impl From<CharSet> for CharSetConfig {
 fn main() {
     let cli = Cli::parse();
 
+    if let Some(Command::Explain { code }) = &cli.command {
+        run_explain(code.as_deref());
+    }
+
     let mut config = match Config::load(cli.config.as_deref()) {
         Ok(cfg) => cfg,
         Err(e) => {
@@ -73,12 +155,35 @@ fn main() {
     if let Some(charset) = cli.charset {
         config.charset = CharSetConfig::from(charset);
     }
+    if cli.max_warnings.is_some() {
+        config.max_warnings = cli.max_warnings;
+    }
+
+    let format = cli.format;
 
     if !config.enabled {
         eprintln!("Validation is disabled in configuration");
+        if format == Format::GitHub {
+            write_github_outputs(&config, Source::Input, &[], true);
+        }
         process::exit(0);
     }
 
+    let output_format = OutputFormat::from(format);
+
+    match cli.command {
+        Some(Command::Pr { title, commits }) => run_pull_request(&config, format, &title, &commits),
+        Some(Command::Check { range, last }) => run_check(&config, format, range, last),
+        Some(Command::Explain { .. }) | None => {}
+    }
+
+    if let Some(path) = cli.commit_msg_file {
+        run_commit_msg_file(&config, format, &path);
+    }
+    if let Some(path) = cli.batch_file {
+        run_batch_file(&config, format, &path);
+    }
+
     let input = match cli.input {
         Some(text) => text,
         None => {
@@ -91,31 +196,739 @@ fn main() {
         }
     };
 
-    let parser = ConventionalParser::new(config.types.clone(), config.scopes.clone());
-    let output_format = OutputFormat::from(cli.format);
+    let linter = Linter::from_config(&config);
+
+    if cli.fix {
+        let fixed = linter.fix_message(&input);
+        let result = linter.lint(&fixed);
+        let outcome = Outcome::from_result("input", &result);
+        let ok = outcome.is_ok() && within_warning_limit(&config, std::slice::from_ref(&outcome));
+
+        // Machine formats describe the fixed message instead of printing it
+        emit_machine_format(
+            format,
+            &config,
+            Source::Input,
+            std::slice::from_ref(&outcome),
+            ok,
+        );
+
+        println!("{fixed}");
+        if outcome.has_diagnostics() {
+            print_report(
+                output_format,
+                &reporter(&config, output_format).report_errors(&fixed, &outcome.diagnostics()),
+            );
+        }
+        if !ok && outcome.is_ok() {
+            eprintln!(
+                "{}",
+                too_many_warnings(&config, std::slice::from_ref(&outcome))
+            );
+        }
+        process::exit(if ok { 0 } else { 1 });
+    }
+
+    let result = linter.lint(&input);
+    let outcome = Outcome::from_result("input", &result);
+    let ok = outcome.is_ok() && within_warning_limit(&config, std::slice::from_ref(&outcome));
+    emit_machine_format(
+        format,
+        &config,
+        Source::Input,
+        std::slice::from_ref(&outcome),
+        ok,
+    );
+
+    if outcome.has_diagnostics() {
+        print_report(
+            output_format,
+            &reporter(&config, output_format).report_errors(&input, &outcome.diagnostics()),
+        );
+    }
+
+    if ok {
+        print_status(output_format, "✓ Valid conventional commit");
+        process::exit(0);
+    }
+    if outcome.is_ok() {
+        eprintln!(
+            "{}",
+            too_many_warnings(&config, std::slice::from_ref(&outcome))
+        );
+    }
+    process::exit(1);
+}
+
+/// What was validated, which selects the document shape of machine-readable formats
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Source {
+    Input,
+    CommitMessageFile,
+    PullRequest,
+    Commits,
+    BatchFile,
+}
+
+impl Source {
+    /// Whether several inputs are reported together
+    fn is_batch(self) -> bool {
+        matches!(
+            self,
+            Source::PullRequest | Source::Commits | Source::BatchFile
+        )
+    }
+
+    fn suite_name(self) -> &'static str {
+        match self {
+            Source::Input => "input",
+            Source::CommitMessageFile => "commit message",
+            Source::PullRequest => "pull request",
+            Source::Commits => "commits",
+            Source::BatchFile => "batch file",
+        }
+    }
+}
+
+/// One validated title or commit message. Error and warning spans index into `input`.
+struct Outcome<'a> {
+    name: String,
+    input: &'a str,
+    message: Option<CommitMessage>,
+    errors: Vec<ParseError>,
+    warnings: Vec<ParseError>,
+}
+
+impl<'a> Outcome<'a> {
+    fn from_result(name: impl Into<String>, result: &ParseResult<'a, CommitMessage>) -> Self {
+        Self {
+            name: name.into(),
+            input: result.input(),
+            message: result.output().cloned(),
+            errors: result
+                .errors()
+                .map(<[ParseError]>::to_vec)
+                .unwrap_or_default(),
+            warnings: result.warnings().to_vec(),
+        }
+    }
+
+    /// Outcome for a checked title or commit, with the parsed message when it is valid
+    fn from_check(name: impl Into<String>, linter: &Linter, check: &MessageCheck<'a>) -> Self {
+        let message = if check.is_ok() {
+            linter.lint(check.input).output().cloned()
+        } else {
+            None
+        };
+        Self {
+            name: name.into(),
+            input: check.input,
+            message,
+            errors: check.errors.clone(),
+            warnings: check.warnings.clone(),
+        }
+    }
+
+    fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    fn has_diagnostics(&self) -> bool {
+        !self.errors.is_empty() || !self.warnings.is_empty()
+    }
+
+    /// Errors followed by warnings
+    fn diagnostics(&self) -> Vec<ParseError> {
+        self.errors.iter().chain(&self.warnings).cloned().collect()
+    }
+
+    fn to_json(&self) -> JsonResult {
+        match &self.message {
+            Some(message) => JsonResult::from_message(self.input, message),
+            None => JsonResult::from_errors(self.input, &self.errors),
+        }
+        .with_warnings(self.input, &self.warnings)
+    }
+}
+
+/// Whether the warnings across all outcomes stay within `maxWarnings`
+fn within_warning_limit(config: &Config, outcomes: &[Outcome]) -> bool {
+    let count: usize = outcomes.iter().map(|outcome| outcome.warnings.len()).sum();
+    config.max_warnings.is_none_or(|max| count <= max)
+}
+
+/// Failure message for otherwise valid input with more warnings than `maxWarnings`
+fn too_many_warnings(config: &Config, outcomes: &[Outcome]) -> String {
+    let count: usize = outcomes.iter().map(|outcome| outcome.warnings.len()).sum();
+    format!(
+        "✗ {count} warning(s) exceed the maximum of {}",
+        config.max_warnings.unwrap_or_default()
+    )
+}
+
+/// Status lines go to stdout for plain-text CI logs and to stderr otherwise
+fn print_status(output_format: OutputFormat, message: &str) {
+    if output_format == OutputFormat::Ascii {
+        println!("{message}");
+    } else {
+        eprintln!("{message}");
+    }
+}
+
+/// Print the document for a machine-readable format and exit with the validation status.
+/// Returns for human-readable formats, after writing the GitHub Actions outputs for
+/// `github`. `ok` is the overall outcome, which for pull requests depends on the mode.
+fn emit_machine_format(
+    format: Format,
+    config: &Config,
+    source: Source,
+    outcomes: &[Outcome],
+    ok: bool,
+) {
+    let document = match format {
+        Format::Default | Format::Markdown | Format::Compact => return,
+        Format::GitHub => {
+            write_github_outputs(config, source, outcomes, ok);
+            return;
+        }
+        Format::Json if source.is_batch() => {
+            let results = outcomes
+                .iter()
+                .map(|outcome| outcome.to_json().with_name(outcome.name.as_str()))
+                .collect();
+            JsonBatchReport::new(ok, results).to_json()
+        }
+        Format::Json => JsonReport::new(outcomes[0].to_json()).to_json(),
+        Format::Junit => {
+            let cases: Vec<_> = outcomes
+                .iter()
+                .map(|outcome| {
+                    JunitCase::new(outcome.name.as_str(), outcome.input, outcome.diagnostics())
+                })
+                .collect();
+            junit_report(source.suite_name(), &cases, config.charset)
+        }
+        Format::Sarif => {
+            let artifacts: Vec<_> = outcomes
+                .iter()
+                .map(|outcome| {
+                    SarifArtifact::new(outcome.name.as_str(), outcome.input, outcome.diagnostics())
+                })
+                .collect();
+            let parser = ConventionalParser::from_config(config);
+            serde_json::to_string_pretty(&sarif_log(&parser, &config.target_url, &artifacts))
+                .expect("SARIF serialization cannot fail")
+        }
+    };
+    println!("{document}");
+    process::exit(if ok { 0 } else { 1 });
+}
+
+fn reporter(config: &Config, output_format: OutputFormat) -> ErrorReporter {
+    ErrorReporter::new(output_format, config.charset).with_target_url(&config.target_url)
+}
+
+/// Markdown goes to stdout so it can be redirected into a comment; reports go to stderr
+fn print_report(output_format: OutputFormat, report: &str) {
+    if output_format == OutputFormat::Markdown {
+        print!("{report}");
+    } else {
+        eprint!("{report}");
+    }
+}
+
+/// Annotate each error and warning with a workflow command, add a Markdown report to the
+/// job summary and set the `valid` and `report` step outputs, like the JS action does.
+fn write_github_outputs(config: &Config, source: Source, outcomes: &[Outcome], ok: bool) {
+    let ascii = reporter(config, OutputFormat::Ascii);
+    let markdown = reporter(config, OutputFormat::Markdown);
+    let mut report = String::new();
+    let mut summary = String::new();
+
+    // Invalid parts don't count when the pull request is valid, but warnings always do
+    for outcome in outcomes {
+        let diagnostics = if ok {
+            outcome.warnings.clone()
+        } else {
+            outcome.diagnostics()
+        };
+        if diagnostics.is_empty() {
+            continue;
+        }
+        let file = (source == Source::CommitMessageFile).then_some(outcome.name.as_str());
+        print!("{}", github::annotations(file, outcome.input, &diagnostics));
+        report.push_str(&ascii.report_errors_named(&outcome.name, outcome.input, &diagnostics));
+        summary.push_str(&markdown.report_errors_named(&outcome.name, outcome.input, &diagnostics));
+    }
+
+    let report = report.trim_end();
+    let mut results = vec![
+        github::set_output("valid", if ok { "true" } else { "false" }),
+        github::set_output("report", report),
+    ];
+    if !summary.is_empty() {
+        results.push(github::append_step_summary(&summary));
+    }
+    for error in results.into_iter().filter_map(Result::err) {
+        eprintln!("Error writing GitHub Actions output: {error}");
+    }
+}
+
+/// Lint a commit message file with its comments and scissors section removed.
+/// Everything is reported against the original file so line numbers match the editor.
+fn commit_msg_file_outcome<'a>(
+    linter: &Linter,
+    name: &str,
+    raw: &'a str,
+    comment_char: &str,
+) -> Outcome<'a> {
+    let cleaned = CleanedMessage::new(raw, comment_char);
+    let result = linter.lint(&cleaned.message);
+    let message = result.output().cloned().map(|mut message| {
+        for trailer in &mut message.trailers {
+            trailer.span = cleaned.original_span(trailer.span.clone());
+        }
+        message
+    });
+    Outcome {
+        name: name.to_string(),
+        input: raw,
+        message,
+        errors: cleaned.original_errors(result.errors().unwrap_or_default()),
+        warnings: cleaned.original_errors(result.warnings()),
+    }
+}
+
+fn run_commit_msg_file(config: &Config, format: Format, path: &Path) -> ! {
+    let output_format = OutputFormat::from(format);
+    let raw = match fs::read_to_string(path) {
+        Ok(raw) => raw,
+        Err(e) => {
+            eprintln!("Error reading commit message file: {e}");
+            process::exit(1);
+        }
+    };
+
+    let name = path.display().to_string();
+    let linter = Linter::from_config(config);
+    let outcome = commit_msg_file_outcome(&linter, &name, &raw, &git::comment_char());
+    let outcomes = std::slice::from_ref(&outcome);
+    let ok = outcome.is_ok() && within_warning_limit(config, outcomes);
+    emit_machine_format(format, config, Source::CommitMessageFile, outcomes, ok);
 
-    let result = parser.parse(&input);
+    if outcome.has_diagnostics() {
+        print_report(
+            output_format,
+            &reporter(config, output_format).report_errors_named(
+                &name,
+                outcome.input,
+                &outcome.diagnostics(),
+            ),
+        );
+    }
 
-    if result.is_ok() {
-        if output_format == OutputFormat::Ascii {
-            println!("✓ Valid conventional commit");
+    if ok {
+        print_status(output_format, "✓ Valid conventional commit");
+        process::exit(0);
+    }
+    if outcome.is_ok() {
+        eprintln!("{}", too_many_warnings(config, outcomes));
+    }
+    process::exit(1);
+}
+
+fn run_pull_request(config: &Config, format: Format, title: &str, commits: &[String]) -> ! {
+    let output_format = OutputFormat::from(format);
+    let commits: Vec<&str> = commits.iter().map(String::as_str).collect();
+    let result = validate_pull_request(config, title, &commits);
+
+    // Only the parts that count towards the outcome for this mode are reported
+    let linter = Linter::from_config(config);
+    let mut outcomes = Vec::new();
+    if result.checks_title() {
+        outcomes.push(Outcome::from_check("title", &linter, &result.title));
+    }
+    if result.checks_commits() {
+        for (idx, commit) in result.commits.iter().enumerate() {
+            outcomes.push(Outcome::from_check(
+                format!("commit {}", idx + 1),
+                &linter,
+                commit,
+            ));
+        }
+    }
+    let ok = result.valid && within_warning_limit(config, &outcomes);
+    emit_machine_format(format, config, Source::PullRequest, &outcomes, ok);
+
+    // Invalid parts don't matter for a valid pull request, but their warnings do
+    let reporter = reporter(config, output_format);
+    for outcome in &outcomes {
+        let diagnostics = if result.valid {
+            outcome.warnings.clone()
         } else {
-            eprintln!("✓ Valid conventional commit");
+            outcome.diagnostics()
+        };
+        if !diagnostics.is_empty() {
+            print_report(
+                output_format,
+                &reporter.report_errors_named(&outcome.name, outcome.input, &diagnostics),
+            );
+        }
+    }
+
+    if ok {
+        print_status(
+            output_format,
+            &format!("✓ Pull request is {}", result.summary()),
+        );
+        process::exit(0);
+    }
+    if result.valid {
+        eprintln!("{}", too_many_warnings(config, &outcomes));
+    } else {
+        eprintln!("✗ Pull request is not semantic: {}", result.summary());
+    }
+    process::exit(1);
+}
+
+fn run_check(config: &Config, format: Format, range: Option<String>, last: Option<usize>) -> ! {
+    let output_format = OutputFormat::from(format);
+    let commits = match (range, last) {
+        (Some(range), _) => git::commits_in_range(&range),
+        (None, Some(count)) => git::last_commits(count),
+        (None, None) => unreachable!("clap requires --range or --last"),
+    };
+    let commits: Vec<Commit> = match commits {
+        Ok(commits) => commits,
+        Err(e) => {
+            eprintln!("Error reading commits: {e}");
+            process::exit(1);
+        }
+    };
+
+    let linter = Linter::from_config(config);
+    let outcomes: Vec<_> = commits
+        .iter()
+        .map(|commit| {
+            let check = check_message(&linter, config, &commit.message);
+            Outcome::from_check(commit.sha.as_str(), &linter, &check)
+        })
+        .collect();
+    let valid = outcomes.iter().all(Outcome::is_ok);
+    let ok = valid && within_warning_limit(config, &outcomes);
+    emit_machine_format(format, config, Source::Commits, &outcomes, ok);
+
+    let reporter = reporter(config, output_format);
+    let mut invalid = 0;
+
+    for (commit, outcome) in commits.iter().zip(&outcomes) {
+        if !outcome.is_ok() {
+            invalid += 1;
+        }
+        if outcome.has_diagnostics() {
+            print_report(
+                output_format,
+                &reporter.report_errors_named(
+                    commit.short_sha(),
+                    outcome.input,
+                    &outcome.diagnostics(),
+                ),
+            );
         }
+    }
+
+    if ok {
+        print_status(
+            output_format,
+            &format!(
+                "✓ {} commit(s) are valid conventional commits",
+                commits.len()
+            ),
+        );
+        process::exit(0);
+    }
+    if valid {
+        eprintln!("{}", too_many_warnings(config, &outcomes));
+    } else {
+        eprintln!(
+            "✗ {invalid} of {} commit(s) are not valid conventional commits",
+            commits.len()
+        );
+    }
+    process::exit(1);
+}
+
+/// Named entries of a batch file: NUL-separated entries when the file has any NUL byte,
+/// otherwise its non-blank lines. Only line terminators are stripped, so whitespace
+/// findings fire like they do for `--input` and `--commit-msg-file`.
+fn batch_entries(contents: &str) -> Vec<(String, &str)> {
+    if contents.contains('\0') {
+        contents
+            .split('\0')
+            .map(|entry| entry.trim_matches(['\n', '\r']))
+            .filter(|entry| !entry.trim().is_empty())
+            .enumerate()
+            .map(|(idx, entry)| (format!("entry {}", idx + 1), entry))
+            .collect()
+    } else {
+        contents
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| (format!("line {}", idx + 1), line))
+            .filter(|(_, line)| !line.trim().is_empty())
+            .collect()
+    }
+}
+
+fn run_batch_file(config: &Config, format: Format, path: &Path) -> ! {
+    let output_format = OutputFormat::from(format);
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) => {
+            eprintln!("Error reading batch file: {e}");
+            process::exit(1);
+        }
+    };
+
+    let linter = Linter::from_config(config);
+    let outcomes: Vec<_> = batch_entries(&contents)
+        .into_iter()
+        .map(|(name, message)| {
+            let check = check_message(&linter, config, message);
+            Outcome::from_check(name, &linter, &check)
+        })
+        .collect();
+    let valid = outcomes.iter().all(Outcome::is_ok);
+    let ok = valid && within_warning_limit(config, &outcomes);
+    emit_machine_format(format, config, Source::BatchFile, &outcomes, ok);
+
+    let reporter = reporter(config, output_format);
+    for outcome in outcomes.iter().filter(|outcome| outcome.has_diagnostics()) {
+        print_report(
+            output_format,
+            &reporter.report_errors_named(&outcome.name, outcome.input, &outcome.diagnostics()),
+        );
+    }
+
+    if ok {
+        print_status(
+            output_format,
+            &format!(
+                "✓ {} message(s) are valid conventional commits",
+                outcomes.len()
+            ),
+        );
         process::exit(0);
+    }
+    if valid {
+        eprintln!("{}", too_many_warnings(config, &outcomes));
     } else {
-        result.print_errors(output_format, config.charset);
-        process::exit(1);
+        let invalid = outcomes.iter().filter(|outcome| !outcome.is_ok()).count();
+        eprintln!(
+            "✗ {invalid} of {} message(s) are not valid conventional commits",
+            outcomes.len()
+        );
+    }
+    process::exit(1);
+}
+
+fn run_explain(code: Option<&str>) -> ! {
+    let Some(code) = code else {
+        for explanation in EXPLANATIONS {
+            println!(
+                "{}  {:<32} {}",
+                explanation.code, explanation.name, explanation.summary
+            );
+        }
+        process::exit(0);
+    };
+
+    match Explanation::find(code) {
+        Some(explanation) => {
+            print!("{}", explanation.render());
+            process::exit(0);
+        }
+        None => {
+            eprintln!("Unknown error code '{code}'. Run `conventional-prs explain` to list codes");
+            process::exit(1);
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use conventional_prs::Severity;
 
     #[test]
     fn test_cli_parsing() {
         use clap::CommandFactory;
         let _ = Cli::command();
     }
+
+    #[test]
+    fn test_pr_subcommand_parsing() {
+        let cli = Cli::parse_from([
+            "conventional-prs",
+            "pr",
+            "--title",
+            "feat: add api",
+            "--commit",
+            "feat: a",
+            "--commit",
+            "fix: b",
+            "--format",
+            "github",
+        ]);
+        match cli.command {
+            Some(Command::Pr { title, commits }) => {
+                assert_eq!(title, "feat: add api");
+                assert_eq!(commits, vec!["feat: a", "fix: b"]);
+            }
+            _ => panic!("expected pr subcommand"),
+        }
+    }
+
+    #[test]
+    fn test_json_format_parsing() {
+        let cli = Cli::parse_from([
+            "conventional-prs",
+            "check",
+            "--last",
+            "1",
+            "--format",
+            "json",
+        ]);
+        assert_eq!(cli.format, Format::Json);
+    }
+
+    #[test]
+    fn test_explain_subcommand_parsing() {
+        let cli = Cli::parse_from(["conventional-prs", "explain", "CP0014"]);
+        assert!(matches!(
+            cli.command,
+            Some(Command::Explain { code: Some(ref code) }) if code == "CP0014"
+        ));
+
+        let cli = Cli::parse_from(["conventional-prs", "explain"]);
+        assert!(matches!(cli.command, Some(Command::Explain { code: None })));
+    }
+
+    #[test]
+    fn test_commit_msg_file_conflicts_with_input() {
+        assert!(
+            Cli::try_parse_from([
+                "conventional-prs",
+                "--input",
+                "feat: x",
+                "--commit-msg-file",
+                ".git/COMMIT_EDITMSG",
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_check_subcommand_requires_range_or_last() {
+        assert!(Cli::try_parse_from(["conventional-prs", "check"]).is_err());
+        assert!(
+            Cli::try_parse_from([
+                "conventional-prs",
+                "check",
+                "--range",
+                "a..b",
+                "--last",
+                "3"
+            ])
+            .is_err()
+        );
+
+        let cli = Cli::parse_from(["conventional-prs", "check", "--last", "3"]);
+        assert!(matches!(
+            cli.command,
+            Some(Command::Check {
+                range: None,
+                last: Some(3)
+            })
+        ));
+    }
+
+    #[test]
+    fn test_batch_file_parsing() {
+        let cli = Cli::parse_from([
+            "conventional-prs",
+            "--batch-file",
+            "titles.txt",
+            "--format",
+            "junit",
+        ]);
+        assert_eq!(cli.batch_file, Some(PathBuf::from("titles.txt")));
+        assert!(
+            Cli::try_parse_from([
+                "conventional-prs",
+                "--batch-file",
+                "titles.txt",
+                "--input",
+                "feat: a",
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_batch_entries_are_lines() {
+        assert_eq!(
+            batch_entries("feat: a\r\n  \n  fix: b  \n"),
+            [
+                ("line 1".to_string(), "feat: a"),
+                ("line 3".to_string(), "  fix: b  ")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_batch_entries_split_on_nul() {
+        assert_eq!(
+            batch_entries("feat: a\n\nbody\n\0\nfix: b\n\0"),
+            [
+                ("entry 1".to_string(), "feat: a\n\nbody"),
+                ("entry 2".to_string(), "fix: b")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_commit_msg_file_warnings_point_into_the_file() {
+        let mut config = Config::default();
+        config.rules.insert(
+            "missing-blank-line-after-header".to_string(),
+            Severity::Warn,
+        );
+        let linter = Linter::from_config(&config);
+        let raw = "# comment one\n# comment two\nfeat: add x\nbody\n";
+        let outcome = commit_msg_file_outcome(&linter, "MSG", raw, "#");
+        assert!(outcome.is_ok());
+        assert!(outcome.message.is_some());
+        assert_eq!(outcome.input, raw);
+        assert_eq!(outcome.warnings.len(), 1);
+        assert_eq!(&raw[outcome.warnings[0].span.start..], "body\n");
+    }
+
+    #[test]
+    fn test_batch_file_and_commit_msg_file_agree() {
+        let config = Config::default();
+        let linter = Linter::from_config(&config);
+        let raw = " feat:  add x\n";
+
+        let entries = batch_entries(raw);
+        assert_eq!(entries.len(), 1);
+        let (name, message) = &entries[0];
+        let check = check_message(&linter, &config, message);
+        let batch = Outcome::from_check(name.clone(), &linter, &check);
+        let commit_msg_file = commit_msg_file_outcome(&linter, "MSG", raw, "#");
+
+        assert!(!batch.errors.is_empty());
+        assert_eq!(batch.errors, commit_msg_file.errors);
+        assert_eq!(batch.warnings, commit_msg_file.warnings);
+    }
 }
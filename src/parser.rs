@@ -1,5 +1,15 @@
 // Parser implementation for Conventional Commit headers with fault-tolerant error collection
 
+use crate::config::{
+    self, DeprecatedConfig, DescriptionCase, LengthUnit, ParserMode, ScopePolicy, Severity,
+    TypeConfig, TypeScopes,
+};
+use crate::explain::Explanation;
+use crate::fix::{self, Suggestion};
+use crate::glob;
+use crate::trailer::{self, Trailer};
+use std::collections::BTreeMap;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct CommitHeader {
     pub commit_type: String,
@@ -8,11 +18,32 @@ pub struct CommitHeader {
     pub description: String,
 }
 
+/// A full commit message: header, optional free-form body and git trailers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommitMessage {
+    pub header: CommitHeader,
+    pub body: Option<String>,
+    pub trailers: Vec<Trailer>,
+}
+
+/// Names of the kinds reported by [`ParseErrorKind::is_house_style`] checks
+const HOUSE_STYLE_KINDS: &[&str] = &[
+    "type-case",
+    "trailing-spaces",
+    "extra-space-after-open-paren",
+    "extra-space-before-close-paren",
+];
+
+/// A header cannot be built without a type or description, so these are always errors
+const ALWAYS_ERRORS: &[&str] = &["empty-type", "missing-description"];
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ParseErrorKind {
     InvalidType {
         found: String,
         expected: Vec<String>,
+        /// Descriptions of the expected types that have one, by name
+        descriptions: BTreeMap<String, String>,
     },
     InvalidScope {
         found: String,
@@ -37,30 +68,284 @@ pub enum ParseErrorKind {
     TrailingSpaces,
     ExtraSpaceAfterOpenParen,
     ExtraSpaceBeforeCloseParen,
+    MissingBlankLineAfterHeader,
+    MissingTrailerSeparator,
+    TrailerTokenWithSpaces {
+        token: String,
+    },
+    DescriptionCase {
+        expected: DescriptionCase,
+    },
+    DescriptionTrailingPeriod,
+    DescriptionTooShort {
+        min: usize,
+        length: usize,
+        unit: LengthUnit,
+    },
+    DescriptionTooLong {
+        max: usize,
+        length: usize,
+        unit: LengthUnit,
+    },
+    HeaderTooLong {
+        max: usize,
+        length: usize,
+        unit: LengthUnit,
+    },
+    DescriptionNotImperative {
+        found: String,
+        imperative: String,
+    },
+    DescriptionRepeatsType {
+        commit_type: String,
+    },
+    VagueDescription,
+    ScopeRequired {
+        commit_type: String,
+        /// Scopes allowed with the type; empty when any scope is
+        expected: Vec<String>,
+    },
+    ScopeForbidden {
+        commit_type: String,
+    },
+    /// A scope that is valid, but not with this type
+    ScopeNotAllowedForType {
+        found: String,
+        commit_type: String,
+        expected: Vec<String>,
+    },
+    /// An alias of a type, e.g. `feature` for `feat`
+    TypeAlias {
+        found: String,
+        canonical: String,
+    },
+    /// An alias of a scope, e.g. `deps` for `dependencies`
+    ScopeAlias {
+        found: String,
+        canonical: String,
+    },
+    /// A deprecated type, accepted with a warning until its `until` date has passed
+    DeprecatedType {
+        found: String,
+        replacement: Option<String>,
+        until: Option<String>,
+        expired: bool,
+    },
+    /// A deprecated scope, accepted with a warning until its `until` date has passed
+    DeprecatedScope {
+        found: String,
+        replacement: Option<String>,
+        until: Option<String>,
+        expired: bool,
+    },
+    /// A configured type written in another case, e.g. `Feat` for `feat`
+    TypeCase {
+        found: String,
+        expected: String,
+    },
+    /// A diagnostic from a [`Rule`](crate::lint::Rule) run by the [`Linter`](crate::lint::Linter)
+    Custom {
+        /// Name of the rule, which doubles as its code
+        rule: String,
+        message: String,
+        label: String,
+        help: Option<String>,
+    },
+}
+
+impl ParseErrorKind {
+    /// Stable kebab-case name for machine-readable output (e.g. `invalid-type`)
+    pub fn name(&self) -> &str {
+        match self {
+            ParseErrorKind::InvalidType { .. } => "invalid-type",
+            ParseErrorKind::InvalidScope { .. } => "invalid-scope",
+            ParseErrorKind::TypeUsedAsScope { .. } => "type-used-as-scope",
+            ParseErrorKind::MissingClosingParen => "missing-closing-paren",
+            ParseErrorKind::MissingSeparator => "missing-separator",
+            ParseErrorKind::MissingDescription => "missing-description",
+            ParseErrorKind::EmptyType => "empty-type",
+            ParseErrorKind::EmptyScope => "empty-scope",
+            ParseErrorKind::UnexpectedChar(_) => "unexpected-char",
+            ParseErrorKind::GenericParseError(_) => "parse-error",
+            ParseErrorKind::ExtraSpaceBeforeColon => "extra-space-before-colon",
+            ParseErrorKind::ExtraSpaceAfterColon => "extra-space-after-colon",
+            ParseErrorKind::MissingColon => "missing-colon",
+            ParseErrorKind::MissingSpace => "missing-space",
+            ParseErrorKind::TrailingSpaces => "trailing-spaces",
+            ParseErrorKind::ExtraSpaceAfterOpenParen => "extra-space-after-open-paren",
+            ParseErrorKind::ExtraSpaceBeforeCloseParen => "extra-space-before-close-paren",
+            ParseErrorKind::MissingBlankLineAfterHeader => "missing-blank-line-after-header",
+            ParseErrorKind::MissingTrailerSeparator => "missing-trailer-separator",
+            ParseErrorKind::TrailerTokenWithSpaces { .. } => "trailer-token-with-spaces",
+            ParseErrorKind::DescriptionCase { .. } => "description-case",
+            ParseErrorKind::DescriptionTrailingPeriod => "description-trailing-period",
+            ParseErrorKind::DescriptionTooShort { .. } => "description-too-short",
+            ParseErrorKind::DescriptionTooLong { .. } => "description-too-long",
+            ParseErrorKind::HeaderTooLong { .. } => "header-too-long",
+            ParseErrorKind::DescriptionNotImperative { .. } => "description-not-imperative",
+            ParseErrorKind::DescriptionRepeatsType { .. } => "description-repeats-type",
+            ParseErrorKind::VagueDescription => "vague-description",
+            ParseErrorKind::ScopeRequired { .. } => "scope-required",
+            ParseErrorKind::ScopeForbidden { .. } => "scope-forbidden",
+            ParseErrorKind::ScopeNotAllowedForType { .. } => "scope-not-allowed-for-type",
+            ParseErrorKind::TypeAlias { .. } => "type-alias",
+            ParseErrorKind::ScopeAlias { .. } => "scope-alias",
+            ParseErrorKind::DeprecatedType { .. } => "deprecated-type",
+            ParseErrorKind::DeprecatedScope { .. } => "deprecated-scope",
+            ParseErrorKind::TypeCase { .. } => "type-case",
+            ParseErrorKind::Custom { rule, .. } => rule,
+        }
+    }
+
+    /// Stable error code (e.g. `CP0001`), explained by `conventional-prs explain`.
+    /// Codes are never reused or renumbered. Custom rules use their name as code.
+    pub fn code(&self) -> &str {
+        match self {
+            ParseErrorKind::InvalidType { .. } => "CP0001",
+            ParseErrorKind::InvalidScope { .. } => "CP0002",
+            ParseErrorKind::TypeUsedAsScope { .. } => "CP0003",
+            ParseErrorKind::MissingClosingParen => "CP0004",
+            ParseErrorKind::MissingSeparator => "CP0005",
+            ParseErrorKind::MissingDescription => "CP0006",
+            ParseErrorKind::EmptyType => "CP0007",
+            ParseErrorKind::EmptyScope => "CP0008",
+            ParseErrorKind::UnexpectedChar(_) => "CP0009",
+            ParseErrorKind::GenericParseError(_) => "CP0010",
+            ParseErrorKind::ExtraSpaceBeforeColon => "CP0011",
+            ParseErrorKind::ExtraSpaceAfterColon => "CP0012",
+            ParseErrorKind::MissingColon => "CP0013",
+            ParseErrorKind::MissingSpace => "CP0014",
+            ParseErrorKind::TrailingSpaces => "CP0015",
+            ParseErrorKind::ExtraSpaceAfterOpenParen => "CP0016",
+            ParseErrorKind::ExtraSpaceBeforeCloseParen => "CP0017",
+            ParseErrorKind::MissingBlankLineAfterHeader => "CP0018",
+            ParseErrorKind::MissingTrailerSeparator => "CP0019",
+            ParseErrorKind::TrailerTokenWithSpaces { .. } => "CP0020",
+            ParseErrorKind::DescriptionCase { .. } => "CP0021",
+            ParseErrorKind::DescriptionTrailingPeriod => "CP0022",
+            ParseErrorKind::DescriptionTooShort { .. } => "CP0023",
+            ParseErrorKind::DescriptionTooLong { .. } => "CP0024",
+            ParseErrorKind::HeaderTooLong { .. } => "CP0025",
+            ParseErrorKind::DescriptionNotImperative { .. } => "CP0026",
+            ParseErrorKind::DescriptionRepeatsType { .. } => "CP0027",
+            ParseErrorKind::VagueDescription => "CP0028",
+            ParseErrorKind::ScopeRequired { .. } => "CP0029",
+            ParseErrorKind::ScopeForbidden { .. } => "CP0030",
+            ParseErrorKind::ScopeNotAllowedForType { .. } => "CP0031",
+            ParseErrorKind::TypeAlias { .. } => "CP0032",
+            ParseErrorKind::ScopeAlias { .. } => "CP0033",
+            ParseErrorKind::DeprecatedType { .. } => "CP0034",
+            ParseErrorKind::DeprecatedScope { .. } => "CP0035",
+            ParseErrorKind::TypeCase { .. } => "CP0036",
+            ParseErrorKind::Custom { rule, .. } => rule,
+        }
+    }
+
+    /// Checks beyond the spec that run without being configured: type case and
+    /// whitespace padding. Spec-strict mode turns these off unless `rules` sets them.
+    pub fn is_house_style(&self) -> bool {
+        HOUSE_STYLE_KINDS.contains(&self.name())
+    }
+
+    /// Severity when `Config::rules` does not set one. Deprecations are warnings until
+    /// they expire; everything else is an error.
+    pub fn default_severity(&self) -> Severity {
+        match self {
+            ParseErrorKind::DeprecatedType { expired, .. }
+            | ParseErrorKind::DeprecatedScope { expired, .. }
+                if !expired =>
+            {
+                Severity::Warn
+            }
+            _ => Severity::Error,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ParseError {
     pub kind: ParseErrorKind,
     pub span: std::ops::Range<usize>,
+    pub suggestion: Option<Suggestion>,
+    /// `Error` or `Warn`; diagnostics for rules that are `Off` are dropped
+    pub severity: Severity,
 }
 
 impl ParseError {
     pub fn new(kind: ParseErrorKind, span: std::ops::Range<usize>) -> Self {
-        Self { kind, span }
+        Self {
+            kind,
+            span,
+            suggestion: None,
+            severity: Severity::Error,
+        }
+    }
+
+    pub fn is_warning(&self) -> bool {
+        self.severity == Severity::Warn
+    }
+
+    /// A diagnostic of a custom [`Rule`](crate::lint::Rule) named `rule`
+    pub fn custom(
+        rule: impl Into<String>,
+        span: std::ops::Range<usize>,
+        message: impl Into<String>,
+        label: impl Into<String>,
+    ) -> Self {
+        Self::new(
+            ParseErrorKind::Custom {
+                rule: rule.into(),
+                message: message.into(),
+                label: label.into(),
+                help: None,
+            },
+            span,
+        )
+    }
+
+    /// Set the help text of a custom diagnostic; other kinds have fixed help texts
+    pub fn with_help(mut self, text: impl Into<String>) -> Self {
+        if let ParseErrorKind::Custom { help, .. } = &mut self.kind {
+            *help = Some(text.into());
+        }
+        self
+    }
+
+    pub fn with_suggestion(mut self, suggestion: Suggestion) -> Self {
+        self.suggestion = Some(suggestion);
+        self
+    }
+
+    /// Move the error (and its suggestion) by `offset` characters
+    pub(crate) fn shift(&mut self, offset: usize) {
+        self.span = (self.span.start + offset)..(self.span.end + offset);
+        if let Some(suggestion) = &mut self.suggestion {
+            suggestion.span = (suggestion.span.start + offset)..(suggestion.span.end + offset);
+        }
     }
 }
 
 /// Parse result that bundles input with output/errors.
 /// Eliminates the need to pass input separately to error reporters.
-pub struct ParseResult<'a> {
+/// Warnings are kept separately, since they don't make the input invalid.
+pub struct ParseResult<'a, T = CommitHeader> {
     input: &'a str,
-    result: Result<CommitHeader, Vec<ParseError>>,
+    result: Result<T, Vec<ParseError>>,
+    warnings: Vec<ParseError>,
 }
 
-impl<'a> ParseResult<'a> {
-    fn new(input: &'a str, result: Result<CommitHeader, Vec<ParseError>>) -> Self {
-        Self { input, result }
+impl<'a, T> ParseResult<'a, T> {
+    pub(crate) fn new(input: &'a str, result: Result<T, Vec<ParseError>>) -> Self {
+        Self {
+            input,
+            result,
+            warnings: Vec::new(),
+        }
+    }
+
+    pub(crate) fn with_warnings(mut self, warnings: Vec<ParseError>) -> Self {
+        self.warnings = warnings;
+        self
     }
 
     pub fn is_ok(&self) -> bool {
@@ -71,7 +356,7 @@ impl<'a> ParseResult<'a> {
         self.result.is_err()
     }
 
-    pub fn output(&self) -> Option<&CommitHeader> {
+    pub fn output(&self) -> Option<&T> {
         self.result.as_ref().ok()
     }
 
@@ -79,19 +364,37 @@ impl<'a> ParseResult<'a> {
         self.result.as_ref().err().map(|e| e.as_slice())
     }
 
-    pub fn input(&self) -> &str {
+    pub fn input(&self) -> &'a str {
         self.input
     }
 
-    pub fn unwrap(self) -> CommitHeader {
+    /// Diagnostics for rules configured as `warn`, whether or not the input is valid
+    pub fn warnings(&self) -> &[ParseError] {
+        &self.warnings
+    }
+
+    /// Errors followed by warnings, for reporting both at once
+    pub fn diagnostics(&self) -> Vec<ParseError> {
+        let mut diagnostics = self
+            .errors()
+            .map(<[ParseError]>::to_vec)
+            .unwrap_or_default();
+        diagnostics.extend(self.warnings.iter().cloned());
+        diagnostics
+    }
+
+    pub fn unwrap(self) -> T {
         self.result.unwrap()
     }
 
-    pub fn into_result(self) -> Result<CommitHeader, Vec<ParseError>> {
+    pub fn into_result(self) -> Result<T, Vec<ParseError>> {
         self.result
     }
 
-    pub fn unwrap_err(self) -> Vec<ParseError> {
+    pub fn unwrap_err(self) -> Vec<ParseError>
+    where
+        T: std::fmt::Debug,
+    {
         self.result.unwrap_err()
     }
 
@@ -100,9 +403,10 @@ impl<'a> ParseResult<'a> {
         format: crate::report::OutputFormat,
         charset: crate::config::CharSetConfig,
     ) -> Option<String> {
-        self.errors().map(|errors| {
+        let diagnostics = self.diagnostics();
+        (!diagnostics.is_empty()).then(|| {
             let reporter = crate::report::ErrorReporter::new(format, charset);
-            reporter.report_errors(self.input, errors)
+            reporter.report_errors(self.input, &diagnostics)
         })
     }
 
@@ -111,9 +415,8 @@ impl<'a> ParseResult<'a> {
         format: crate::report::OutputFormat,
         charset: crate::config::CharSetConfig,
     ) {
-        if let Some(errors) = self.errors() {
-            let reporter = crate::report::ErrorReporter::new(format, charset);
-            eprint!("{}", reporter.report_errors(self.input, errors));
+        if let Some(report) = self.report(format, charset) {
+            eprint!("{report}");
         }
     }
 }
@@ -121,17 +424,160 @@ impl<'a> ParseResult<'a> {
 pub struct ConventionalParser {
     allowed_types: Vec<String>,
     allowed_scopes: Option<Vec<String>>,
+    type_descriptions: BTreeMap<String, String>,
+    type_scopes: BTreeMap<String, TypeScopes>,
+    type_aliases: BTreeMap<String, String>,
+    scope_aliases: BTreeMap<String, String>,
+    deprecated: DeprecatedConfig,
+    /// `YYYY-MM-DD`, compared with the `until` dates of deprecations
+    today: String,
+    mode: ParserMode,
+    rules: BTreeMap<String, Severity>,
 }
 
 impl ConventionalParser {
-    pub fn new(allowed_types: Vec<String>, allowed_scopes: Option<Vec<String>>) -> Self {
+    /// Parser accepting `types`, given as names or as [`TypeConfig`]s with metadata, and
+    /// `allowed_scopes` (any scope when `None`)
+    pub fn new<T: Into<TypeConfig>>(
+        types: impl IntoIterator<Item = T>,
+        allowed_scopes: Option<Vec<String>>,
+    ) -> Self {
+        let types: Vec<TypeConfig> = types.into_iter().map(Into::into).collect();
         Self {
-            allowed_types,
+            allowed_types: types.iter().map(|t| t.name.clone()).collect(),
             allowed_scopes,
+            type_descriptions: types
+                .into_iter()
+                .filter_map(|t| Some((t.name, t.description?)))
+                .collect(),
+            type_scopes: BTreeMap::new(),
+            type_aliases: BTreeMap::new(),
+            scope_aliases: BTreeMap::new(),
+            deprecated: DeprecatedConfig::default(),
+            today: config::today(),
+            mode: ParserMode::default(),
+            rules: BTreeMap::new(),
+        }
+    }
+
+    /// Parser for the types, scopes and rule severities of a configuration
+    pub fn from_config(config: &crate::config::Config) -> Self {
+        Self::new(config.type_configs(), config.scopes.clone())
+            .with_type_scopes(config.type_scopes.clone())
+            .with_aliases(config.type_aliases.clone(), config.scope_aliases.clone())
+            .with_deprecated(config.deprecated.clone())
+            .with_mode(config.mode)
+            .with_rules(config.rules.clone())
+    }
+
+    /// In spec-strict mode, house-style checks are off unless `rules` turns them on and
+    /// scopes match case-insensitively
+    pub fn with_mode(mut self, mode: ParserMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Accept deprecated types and scopes with a warning, or an error once expired
+    pub fn with_deprecated(mut self, deprecated: DeprecatedConfig) -> Self {
+        self.deprecated = deprecated;
+        self
+    }
+
+    /// Evaluate deprecation dates as of `today` (`YYYY-MM-DD`) instead of the current date
+    pub fn with_today(mut self, today: impl Into<String>) -> Self {
+        self.today = today.into();
+        self
+    }
+
+    /// Accept type and scope aliases, keyed by alias, and resolve them to their canonical
+    /// names in parsed headers. Each use is still reported, as `type-alias`/`scope-alias`.
+    pub fn with_aliases(
+        mut self,
+        type_aliases: BTreeMap<String, String>,
+        scope_aliases: BTreeMap<String, String>,
+    ) -> Self {
+        self.type_aliases = type_aliases;
+        self.scope_aliases = scope_aliases;
+        self
+    }
+
+    /// Set scope policies and scope lists per type (see `Config::type_scopes`)
+    pub fn with_type_scopes(mut self, type_scopes: BTreeMap<String, TypeScopes>) -> Self {
+        self.type_scopes = type_scopes;
+        self
+    }
+
+    /// Scopes allowed with a type; `None` when any scope is
+    fn scopes_for(&self, commit_type: &str) -> Option<&Vec<String>> {
+        self.type_scopes
+            .get(commit_type)
+            .and_then(|policy| policy.scopes.as_ref())
+            .or(self.allowed_scopes.as_ref())
+    }
+
+    /// Override rule severities, keyed by kind name or code (see `Config::rules`)
+    pub fn with_rules(mut self, rules: BTreeMap<String, Severity>) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// Override the severity of one rule, keeping the others
+    pub fn with_rule_severity(mut self, rule: impl Into<String>, severity: Severity) -> Self {
+        self.rules.insert(rule.into(), severity);
+        self
+    }
+
+    /// Configured severity of an error kind. A header cannot be built without a type or
+    /// description, so `empty-type` and `missing-description` are always errors.
+    pub fn severity(&self, kind: &ParseErrorKind) -> Severity {
+        self.set_severity(kind.name(), kind.code())
+            .unwrap_or_else(|| kind.default_severity())
+    }
+
+    /// Configured severity of a built-in rule without a diagnostic at hand, e.g. to list
+    /// every rule. Deprecations count as not expired yet.
+    pub fn rule_severity(&self, explanation: &Explanation) -> Severity {
+        self.set_severity(explanation.name, explanation.code)
+            .unwrap_or(match explanation.name {
+                "deprecated-type" | "deprecated-scope" => Severity::Warn,
+                _ => Severity::Error,
+            })
+    }
+
+    /// Severity set by `rules` or the parser mode; `None` when the kind's default applies
+    fn set_severity(&self, name: &str, code: &str) -> Option<Severity> {
+        if ALWAYS_ERRORS.contains(&name) {
+            return Some(Severity::Error);
+        }
+        self.rules
+            .get(name)
+            .or_else(|| self.rules.get(code))
+            .copied()
+            .or_else(|| {
+                (self.mode == ParserMode::SpecStrict && HOUSE_STYLE_KINDS.contains(&name))
+                    .then_some(Severity::Off)
+            })
+    }
+
+    /// Split diagnostics into errors and warnings, dropping those that are turned off
+    pub(crate) fn apply_rules(
+        &self,
+        diagnostics: Vec<ParseError>,
+    ) -> (Vec<ParseError>, Vec<ParseError>) {
+        let mut errors = Vec::new();
+        let mut warnings = Vec::new();
+        for mut diagnostic in diagnostics {
+            diagnostic.severity = self.severity(&diagnostic.kind);
+            match diagnostic.severity {
+                Severity::Error => errors.push(diagnostic),
+                Severity::Warn => warnings.push(diagnostic),
+                Severity::Off => {}
+            }
         }
+        (errors, warnings)
     }
 
-    fn strip_git_autosquash_prefixes(input: &str) -> (&str, usize) {
+    pub(crate) fn strip_git_autosquash_prefixes(input: &str) -> (&str, usize) {
         let mut s = input;
         let mut offset = 0;
 
@@ -158,69 +604,341 @@ impl ConventionalParser {
     /// Returns a ParseResult that bundles input with output/errors.
     pub fn parse<'a>(&self, input: &'a str) -> ParseResult<'a> {
         let (effective_input, offset) = Self::strip_git_autosquash_prefixes(input);
-        let mut result = self.parse_internal(effective_input);
+        let (header, mut diagnostics) = self.parse_internal(effective_input);
+
+        for diagnostic in diagnostics.iter_mut() {
+            diagnostic.shift(offset);
+        }
+        fix::attach_suggestions(input, &mut diagnostics);
+        let (errors, warnings) = self.apply_rules(diagnostics);
+
+        // Errors that prevent building a header are never downgraded, so a missing
+        // header always comes with errors
+        let result = match header {
+            Some(header) if errors.is_empty() => Ok(header),
+            _ => Err(errors),
+        };
+
+        ParseResult::new(input, result).with_warnings(warnings)
+    }
 
-        if offset != 0
-            && let Err(ref mut errors) = result
+    /// Parse a full commit message (header, body and trailers) with fault tolerance.
+    /// Error spans are relative to the whole message, so they can point past the header.
+    pub fn parse_message<'a>(&self, input: &'a str) -> ParseResult<'a, CommitMessage> {
+        let lines = Self::split_lines(input);
+        let (_, header_line) = lines[0];
+
+        let header_result = self.parse(header_line);
+        let mut warnings = header_result.warnings().to_vec();
+        let (header, mut errors) = match header_result.into_result() {
+            Ok(header) => (Some(header), Vec::new()),
+            Err(errors) => (None, errors),
+        };
+
+        let mut diagnostics = Vec::new();
+
+        // The spec requires a blank line between the header and the body
+        if let Some(&(start, line)) = lines.get(1)
+            && !line.trim().is_empty()
         {
-            for error in errors {
-                error.span = (error.span.start + offset)..(error.span.end + offset);
-            }
+            diagnostics.push(ParseError::new(
+                ParseErrorKind::MissingBlankLineAfterHeader,
+                start..start + line.chars().count(),
+            ));
         }
 
-        ParseResult::new(input, result)
+        let mut content = &lines[1..];
+        while let Some(((_, line), rest)) = content.split_first()
+            && line.trim().is_empty()
+        {
+            content = rest;
+        }
+        while let Some(((_, line), rest)) = content.split_last()
+            && line.trim().is_empty()
+        {
+            content = rest;
+        }
+
+        let (body_lines, trailer_lines) = content.split_at(trailer::find_trailer_block(content));
+        let (trailers, trailer_errors) = trailer::parse_trailers(trailer_lines);
+        diagnostics.extend(trailer_errors);
+
+        fix::attach_suggestions(input, &mut diagnostics);
+        let (body_errors, body_warnings) = self.apply_rules(diagnostics);
+        errors.extend(body_errors);
+        warnings.extend(body_warnings);
+
+        let body_text = body_lines
+            .iter()
+            .map(|(_, line)| *line)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let body_text = body_text.trim_end();
+        let body = (!body_text.is_empty()).then(|| body_text.to_string());
+
+        let result = match header {
+            Some(mut header) if errors.is_empty() => {
+                header.breaking |= trailers.iter().any(Trailer::is_breaking_change);
+                Ok(CommitMessage {
+                    header,
+                    body,
+                    trailers,
+                })
+            }
+            _ => Err(errors),
+        };
+
+        ParseResult::new(input, result).with_warnings(warnings)
+    }
+
+    /// Repeatedly apply suggested fixes for errors and warnings until none are left or
+    /// no fix applies. Suggestions with placeholders are skipped, so the result may still
+    /// be invalid.
+    pub fn fix_message(&self, input: &str) -> String {
+        fix::fix_until_stable(input, |message| self.parse_message(message).diagnostics())
     }
 
-    fn parse_internal(&self, input: &str) -> Result<CommitHeader, Vec<ParseError>> {
-        let (header_opt, mut all_errors) = self.manual_parse(input);
+    /// Split input into lines, pairing each with its character offset.
+    /// A trailing '\r' is dropped so CRLF messages behave like LF ones.
+    fn split_lines(input: &str) -> Vec<(usize, &str)> {
+        let mut lines = Vec::new();
+        let mut offset = 0;
+        for line in input.split('\n') {
+            lines.push((offset, line.strip_suffix('\r').unwrap_or(line)));
+            offset += line.chars().count() + 1;
+        }
+        lines
+    }
+
+    /// Parse a header and validate its type and scopes. Returns the header whenever one
+    /// could be built, together with every diagnostic regardless of its severity.
+    fn parse_internal(&self, input: &str) -> (Option<CommitHeader>, Vec<ParseError>) {
+        let (mut header_opt, mut all_errors) = self.manual_parse(input);
 
-        if let Some(header) = &header_opt {
-            if !self.allowed_types.contains(&header.commit_type) {
+        if let Some(header) = &mut header_opt {
+            // Spans point at the text as written, before aliases are resolved
+            let type_len = header.commit_type.chars().count();
+            let scope_spans = Self::scope_spans(input, type_len);
+            self.resolve_type_case(header, type_len, &mut all_errors);
+            self.resolve_aliases(header, type_len, &scope_spans, &mut all_errors);
+
+            if let Some(deprecation) = self.deprecated.types.get(&header.commit_type) {
+                all_errors.push(ParseError::new(
+                    ParseErrorKind::DeprecatedType {
+                        found: header.commit_type.clone(),
+                        replacement: deprecation.replacement.clone(),
+                        until: deprecation.until.clone(),
+                        expired: deprecation.is_expired(&self.today),
+                    },
+                    0..type_len,
+                ));
+            } else if !self.allowed_types.contains(&header.commit_type) {
                 all_errors.push(ParseError::new(
                     ParseErrorKind::InvalidType {
                         found: header.commit_type.clone(),
                         expected: self.allowed_types.clone(),
+                        descriptions: self.type_descriptions.clone(),
                     },
-                    0..header.commit_type.len(),
+                    0..type_len,
                 ));
             }
 
-            if let Some(ref allowed_scopes) = self.allowed_scopes
-                && let Some(ref scopes) = header.scope
-            {
-                // Validate each scope individually
-                let mut scope_pos = header.commit_type.len() + 2; // +2 for '(' and initial offset
-                for (i, individual_scope) in scopes.iter().enumerate() {
-                    if !allowed_scopes.contains(individual_scope) {
-                        // Check if this invalid scope is actually a valid type being misused
-                        if self.allowed_types.contains(individual_scope) {
-                            all_errors.push(ParseError::new(
-                                ParseErrorKind::TypeUsedAsScope {
-                                    found: individual_scope.clone(),
-                                    expected_scopes: allowed_scopes.clone(),
-                                    available_types: self.allowed_types.clone(),
-                                },
-                                scope_pos..scope_pos + individual_scope.len(),
-                            ));
-                        } else {
-                            all_errors.push(ParseError::new(
-                                ParseErrorKind::InvalidScope {
-                                    found: individual_scope.clone(),
-                                    expected: allowed_scopes.clone(),
-                                },
-                                scope_pos..scope_pos + individual_scope.len(),
-                            ));
-                        }
-                    }
-                    scope_pos += individual_scope.len() + if i < scopes.len() - 1 { 2 } else { 0 }; // +2 for ', '
-                }
+            self.check_scopes(input, header, type_len, &scope_spans, &mut all_errors);
+        }
+
+        (header_opt, all_errors)
+    }
+
+    /// Spans of the comma-separated scopes in `type(scope, ...)`, trimmed like the scopes
+    fn scope_spans(input: &str, type_len: usize) -> Vec<std::ops::Range<usize>> {
+        if input.chars().nth(type_len) != Some('(') {
+            return Vec::new();
+        }
+        let scope_text: String = input
+            .chars()
+            .skip(type_len + 1)
+            .take_while(|&c| c != ')')
+            .collect();
+        let mut part_start = type_len + 1;
+        scope_text
+            .split(',')
+            .map(|part| {
+                let start = part_start + part.chars().take_while(|c| c.is_whitespace()).count();
+                part_start += part.chars().count() + 1;
+                start..start + part.trim().chars().count()
+            })
+            .collect()
+    }
+
+    /// Types are case-insensitive in the spec: replace a type that only differs in case
+    /// from a configured type, alias or deprecated type with the configured spelling,
+    /// reporting it as `type-case`
+    fn resolve_type_case(
+        &self,
+        header: &mut CommitHeader,
+        type_len: usize,
+        errors: &mut Vec<ParseError>,
+    ) {
+        let mut known = self
+            .allowed_types
+            .iter()
+            .chain(self.type_aliases.keys())
+            .chain(self.deprecated.types.keys());
+        if known.clone().any(|known| *known == header.commit_type) {
+            return;
+        }
+        let lowercase = header.commit_type.to_lowercase();
+        if let Some(expected) = known.find(|known| known.to_lowercase() == lowercase) {
+            errors.push(ParseError::new(
+                ParseErrorKind::TypeCase {
+                    found: header.commit_type.clone(),
+                    expected: expected.clone(),
+                },
+                0..type_len,
+            ));
+            header.commit_type = expected.clone();
+        }
+    }
+
+    /// Replace type and scope aliases in the header with their canonical names,
+    /// reporting each one
+    fn resolve_aliases(
+        &self,
+        header: &mut CommitHeader,
+        type_len: usize,
+        scope_spans: &[std::ops::Range<usize>],
+        errors: &mut Vec<ParseError>,
+    ) {
+        if !self.allowed_types.contains(&header.commit_type)
+            && let Some(canonical) = self.type_aliases.get(&header.commit_type)
+        {
+            errors.push(ParseError::new(
+                ParseErrorKind::TypeAlias {
+                    found: header.commit_type.clone(),
+                    canonical: canonical.clone(),
+                },
+                0..type_len,
+            ));
+            header.commit_type = canonical.clone();
+        }
+
+        let allowed_scopes = self.scopes_for(&header.commit_type);
+        for (scope, span) in header.scope.iter_mut().flatten().zip(scope_spans) {
+            if allowed_scopes.is_some_and(|allowed| glob::matches_any(allowed, scope)) {
+                continue;
+            }
+            if let Some(canonical) = self.scope_aliases.get(scope.as_str()) {
+                errors.push(ParseError::new(
+                    ParseErrorKind::ScopeAlias {
+                        found: scope.clone(),
+                        canonical: canonical.clone(),
+                    },
+                    span.clone(),
+                ));
+                *scope = canonical.clone();
             }
         }
+    }
 
-        if all_errors.is_empty() {
-            Ok(header_opt.unwrap())
-        } else {
-            Err(all_errors)
+    /// Check the scopes of a header against the scope lists and the policy of its type
+    fn check_scopes(
+        &self,
+        input: &str,
+        header: &CommitHeader,
+        type_len: usize,
+        scope_spans: &[std::ops::Range<usize>],
+        errors: &mut Vec<ParseError>,
+    ) {
+        let commit_type = &header.commit_type;
+        let policy = self
+            .type_scopes
+            .get(commit_type)
+            .map(|policy| policy.scope)
+            .unwrap_or_default();
+        let allowed_scopes = self.scopes_for(commit_type);
+
+        let Some(scopes) = &header.scope else {
+            let has_parens = input.chars().nth(type_len) == Some('(');
+            // `type()` is already reported as an empty scope
+            if policy == ScopePolicy::Required && !has_parens {
+                errors.push(ParseError::new(
+                    ParseErrorKind::ScopeRequired {
+                        commit_type: commit_type.clone(),
+                        expected: allowed_scopes.cloned().unwrap_or_default(),
+                    },
+                    0..type_len,
+                ));
+            }
+            return;
+        };
+
+        if policy == ScopePolicy::Forbidden {
+            let end = scope_spans.last().map_or(type_len + 1, |span| span.end);
+            let closing = input.chars().skip(end).position(|c| c == ')');
+            errors.push(ParseError::new(
+                ParseErrorKind::ScopeForbidden {
+                    commit_type: commit_type.clone(),
+                },
+                type_len..closing.map_or(end, |idx| end + idx + 1),
+            ));
+            return;
+        }
+
+        for (individual_scope, span) in scopes.iter().zip(scope_spans) {
+            let span = span.clone();
+            if let Some(deprecation) = self.deprecated.scopes.get(individual_scope) {
+                errors.push(ParseError::new(
+                    ParseErrorKind::DeprecatedScope {
+                        found: individual_scope.clone(),
+                        replacement: deprecation.replacement.clone(),
+                        until: deprecation.until.clone(),
+                        expired: deprecation.is_expired(&self.today),
+                    },
+                    span,
+                ));
+                continue;
+            }
+            let Some(allowed_scopes) = allowed_scopes else {
+                continue;
+            };
+            if glob::matches_any(allowed_scopes, individual_scope)
+                || (self.mode == ParserMode::SpecStrict
+                    && glob::matches_any(
+                        &allowed_scopes
+                            .iter()
+                            .map(|scope| scope.to_lowercase())
+                            .collect::<Vec<_>>(),
+                        &individual_scope.to_lowercase(),
+                    ))
+            {
+                continue;
+            }
+
+            let kind = if self.allowed_types.contains(individual_scope) {
+                // A valid type being misused as a scope
+                ParseErrorKind::TypeUsedAsScope {
+                    found: individual_scope.clone(),
+                    expected_scopes: allowed_scopes.clone(),
+                    available_types: self.allowed_types.clone(),
+                }
+            } else if self.allowed_types.iter().any(|other| {
+                other != commit_type
+                    && self
+                        .scopes_for(other)
+                        .is_none_or(|scopes| glob::matches_any(scopes, individual_scope))
+            }) {
+                ParseErrorKind::ScopeNotAllowedForType {
+                    found: individual_scope.clone(),
+                    commit_type: commit_type.clone(),
+                    expected: allowed_scopes.clone(),
+                }
+            } else {
+                ParseErrorKind::InvalidScope {
+                    found: individual_scope.clone(),
+                    expected: allowed_scopes.clone(),
+                }
+            };
+            errors.push(ParseError::new(kind, span));
         }
     }
 
@@ -378,7 +1096,7 @@ impl ConventionalParser {
         if trimmed_description.is_empty() {
             errors.push(ParseError::new(
                 ParseErrorKind::MissingDescription,
-                input.len()..input.len(),
+                chars.len()..chars.len(),
             ));
             // Don't return early - still report other errors
         }
@@ -390,10 +1108,10 @@ impl ConventionalParser {
 
         // Check for trailing spaces at end of input
         if description != trimmed_description && description.ends_with(' ') {
-            let trailing_start = desc_start + description.trim_end().len();
+            let trailing_start = desc_start + description.trim_end().chars().count();
             errors.push(ParseError::new(
                 ParseErrorKind::TrailingSpaces,
-                trailing_start..input.len(),
+                trailing_start..chars.len(),
             ));
         }
 
@@ -414,8 +1132,10 @@ impl ConventionalParser {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison, clippy::len_zero)]
 mod tests {
     use super::*;
+    use crate::config::Deprecation;
 
     fn default_parser() -> ConventionalParser {
         ConventionalParser::new(
@@ -1730,4 +2450,619 @@ mod tests {
             assert_eq!(header.scope, Some(vec!["api".to_string()]));
         }
     }
+
+    // ===== FULL MESSAGE TESTS =====
+
+    #[test]
+    fn test_message_header_only() {
+        let parser = default_parser();
+        let result = parser.parse_message("feat: add new feature");
+        assert!(result.is_ok());
+        let message = result.unwrap();
+        assert_eq!(message.header.description, "add new feature");
+        assert_eq!(message.body, None);
+        assert!(message.trailers.is_empty());
+    }
+
+    #[test]
+    fn test_message_with_body() {
+        let parser = default_parser();
+        let result = parser.parse_message(
+            "fix: prevent racing\n\nIntroduce a request id.\nDismiss stale responses.",
+        );
+        assert!(result.is_ok());
+        let message = result.unwrap();
+        assert_eq!(
+            message.body,
+            Some("Introduce a request id.\nDismiss stale responses.".to_string())
+        );
+        assert!(message.trailers.is_empty());
+    }
+
+    #[test]
+    fn test_message_with_multi_paragraph_body_and_trailers() {
+        let parser = default_parser();
+        let input = "fix: prevent racing\n\nFirst paragraph.\n\nSecond paragraph.\n\nReviewed-by: Z\nRefs #123";
+        let result = parser.parse_message(input);
+        assert!(result.is_ok());
+        let message = result.unwrap();
+        assert_eq!(
+            message.body,
+            Some("First paragraph.\n\nSecond paragraph.".to_string())
+        );
+        assert_eq!(message.trailers.len(), 2);
+        assert_eq!(message.trailers[0].token, "Reviewed-by");
+        assert_eq!(message.trailers[0].value, "Z");
+        assert_eq!(message.trailers[1].token, "Refs");
+        assert_eq!(message.trailers[1].value, "123");
+    }
+
+    #[test]
+    fn test_message_trailer_spans_are_relative_to_message() {
+        let parser = default_parser();
+        let input = "feat: add api\n\nRefs: #1";
+        let message = parser.parse_message(input).unwrap();
+        assert_eq!(message.trailers[0].span, 15..23);
+    }
+
+    #[test]
+    fn test_message_trailer_value_continuation() {
+        let parser = default_parser();
+        let input = "feat: add api\n\nBREAKING CHANGE: first line\n  second line\nRefs: #1";
+        let message = parser.parse_message(input).unwrap();
+        assert_eq!(message.trailers.len(), 2);
+        assert_eq!(message.trailers[0].value, "first line\nsecond line");
+    }
+
+    #[test]
+    fn test_message_breaking_change_trailer_sets_breaking() {
+        let parser = default_parser();
+        let input = "feat: allow config to extend\n\nBREAKING CHANGE: `extends` key is now used";
+        let message = parser.parse_message(input).unwrap();
+        assert!(message.header.breaking);
+        assert!(message.trailers[0].is_breaking_change());
+    }
+
+    #[test]
+    fn test_message_breaking_change_hyphen_trailer_sets_breaking() {
+        let parser = default_parser();
+        let input = "feat: allow config to extend\n\nBREAKING-CHANGE: `extends` key is now used";
+        let message = parser.parse_message(input).unwrap();
+        assert!(message.header.breaking);
+    }
+
+    #[test]
+    fn test_message_breaking_change_in_body_is_not_a_trailer() {
+        let parser = default_parser();
+        let input = "feat: add api\n\nBREAKING CHANGE: not really\n\nJust a body paragraph.";
+        let message = parser.parse_message(input).unwrap();
+        assert!(!message.header.breaking);
+        assert!(message.trailers.is_empty());
+    }
+
+    #[test]
+    fn test_message_missing_blank_line_after_header() {
+        let parser = default_parser();
+        let input = "feat: add api\nbody without separator";
+        let result = parser.parse_message(input);
+        assert!(result.is_err());
+        let errors = result.unwrap_err();
+        let error = errors
+            .iter()
+            .find(|e| matches!(e.kind, ParseErrorKind::MissingBlankLineAfterHeader))
+            .expect("expected missing blank line error");
+        assert_eq!(error.span, 14..36);
+    }
+
+    #[test]
+    fn test_message_header_errors_still_reported() {
+        let parser = default_parser();
+        let result = parser.parse_message("fature: typo\n\nbody");
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .iter()
+                .any(|e| matches!(&e.kind, ParseErrorKind::InvalidType { .. }))
+        );
+    }
+
+    #[test]
+    fn test_message_crlf_line_endings() {
+        let parser = default_parser();
+        let input = "feat: add api\r\n\r\nbody text\r\n\r\nRefs: #1\r\n";
+        let message = parser.parse_message(input).unwrap();
+        assert_eq!(message.header.description, "add api");
+        assert_eq!(message.body, Some("body text".to_string()));
+        assert_eq!(message.trailers[0].value, "#1");
+    }
+
+    #[test]
+    fn test_message_malformed_trailers_are_reported() {
+        let parser = default_parser();
+        let input =
+            "feat: add api\n\nRefs: #1\nAcked-by: Carol\nReviewed by: Alice\nCo-authored-by Bob";
+        let result = parser.parse_message(input);
+        assert!(result.is_err());
+        let errors = result.unwrap_err();
+        assert!(errors.iter().any(|e| matches!(
+            &e.kind,
+            ParseErrorKind::TrailerTokenWithSpaces { token } if token == "Reviewed by"
+        )));
+        assert!(
+            errors
+                .iter()
+                .any(|e| matches!(e.kind, ParseErrorKind::MissingTrailerSeparator))
+        );
+    }
+
+    // ===== RULE SEVERITY TESTS =====
+
+    fn parser_with_rules(rules: &[(&str, Severity)]) -> ConventionalParser {
+        default_parser().with_rules(
+            rules
+                .iter()
+                .map(|(key, severity)| (key.to_string(), *severity))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_warnings_keep_the_header_valid() {
+        let parser = parser_with_rules(&[("trailing-spaces", Severity::Warn)]);
+        let result = parser.parse("feat: add api  ");
+        assert!(result.is_ok());
+        assert_eq!(result.warnings().len(), 1);
+        assert!(result.warnings()[0].is_warning());
+        assert!(result.warnings()[0].suggestion.is_some());
+        assert_eq!(result.unwrap().description, "add api");
+    }
+
+    #[test]
+    fn test_rules_accept_codes_and_off() {
+        let parser = parser_with_rules(&[("CP0012", Severity::Off)]);
+        let result = parser.parse("feat:  add api");
+        assert!(result.is_ok());
+        assert!(result.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_warnings_are_kept_alongside_errors() {
+        let parser = parser_with_rules(&[("trailing-spaces", Severity::Warn)]);
+        let result = parser.parse("feta: add api  ");
+        assert_eq!(result.errors().unwrap().len(), 1);
+        assert_eq!(result.warnings().len(), 1);
+        assert_eq!(result.diagnostics().len(), 2);
+    }
+
+    #[test]
+    fn test_header_errors_cannot_be_downgraded() {
+        let parser = parser_with_rules(&[("missing-description", Severity::Off)]);
+        assert!(parser.parse("feat: ").is_err());
+    }
+
+    #[test]
+    fn test_message_rules_apply_to_body_errors() {
+        let parser = parser_with_rules(&[("missing-blank-line-after-header", Severity::Warn)]);
+        let result = parser.parse_message("feat: add api\nbody");
+        assert!(result.is_ok());
+        assert_eq!(
+            result.warnings()[0].kind,
+            ParseErrorKind::MissingBlankLineAfterHeader
+        );
+    }
+
+    #[test]
+    fn test_fix_message_fixes_warnings() {
+        let parser = parser_with_rules(&[("trailing-spaces", Severity::Warn)]);
+        assert_eq!(parser.fix_message("feat: add api  "), "feat: add api");
+    }
+
+    // ===== SUGGESTION TESTS =====
+
+    #[test]
+    fn test_errors_carry_suggestions() {
+        let parser = default_parser();
+        let errors = parser.parse("feat :  description ").unwrap_err();
+        assert!(errors.iter().all(|e| e.suggestion.is_some()));
+    }
+
+    #[test]
+    fn test_suggestion_spans_are_offset_for_fixup_prefix() {
+        let parser = default_parser();
+        let errors = parser.parse("fixup! feat : description").unwrap_err();
+        let suggestion = errors[0].suggestion.as_ref().unwrap();
+        assert_eq!(suggestion.span, 11..12);
+    }
+
+    #[test]
+    fn test_fix_message_spacing() {
+        let parser = default_parser();
+        assert_eq!(parser.fix_message("feat  :   add api  "), "feat: add api");
+        assert_eq!(
+            parser.fix_message("feat( api ):add api"),
+            "feat(api): add api"
+        );
+    }
+
+    #[test]
+    fn test_fix_message_missing_paren_and_colon() {
+        let parser = default_parser();
+        assert_eq!(
+            parser.fix_message("feat(api: add api"),
+            "feat(api): add api"
+        );
+        assert_eq!(parser.fix_message("feat add api"), "feat: add api");
+    }
+
+    #[test]
+    fn test_fix_message_similar_type() {
+        let parser = default_parser();
+        assert_eq!(parser.fix_message("featt: add api"), "feat: add api");
+    }
+
+    #[test]
+    fn test_fix_message_body_and_trailers() {
+        let parser = default_parser();
+        assert_eq!(
+            parser.fix_message("feat: add api\nbody\n\nRefs: #1\nReviewed by: Bob"),
+            "feat: add api\n\nbody\n\nRefs: #1\nReviewed-by: Bob"
+        );
+    }
+
+    #[test]
+    fn test_fix_message_leaves_placeholders_alone() {
+        let parser = default_parser();
+        assert_eq!(parser.fix_message("feat: "), "feat: ");
+    }
+
+    // ===== TYPE SCOPE POLICY TESTS =====
+
+    fn monorepo_parser() -> ConventionalParser {
+        let policy = |scope, scopes: Option<&[&str]>| TypeScopes {
+            scope,
+            scopes: scopes.map(|scopes| scopes.iter().map(|s| s.to_string()).collect()),
+        };
+        ConventionalParser::new(
+            vec![
+                "feat".to_string(),
+                "fix".to_string(),
+                "docs".to_string(),
+                "build".to_string(),
+                "ci".to_string(),
+            ],
+            Some(vec!["api".to_string(), "ui".to_string()]),
+        )
+        .with_type_scopes(BTreeMap::from([
+            ("feat".to_string(), policy(ScopePolicy::Required, None)),
+            ("ci".to_string(), policy(ScopePolicy::Forbidden, None)),
+            (
+                "build".to_string(),
+                policy(ScopePolicy::Optional, Some(&["api", "ui", "deps"])),
+            ),
+        ]))
+    }
+
+    #[test]
+    fn test_scope_required() {
+        let parser = monorepo_parser();
+        let errors = parser.parse("feat: add login").unwrap_err();
+        assert_eq!(
+            errors[0].kind,
+            ParseErrorKind::ScopeRequired {
+                commit_type: "feat".to_string(),
+                expected: vec!["api".to_string(), "ui".to_string()],
+            }
+        );
+        assert_eq!(errors[0].span, 0..4);
+        assert!(parser.parse("feat(api): add login").is_ok());
+        assert!(parser.parse("docs: add login guide").is_ok());
+        // `feat()` is only reported as an empty scope
+        let errors = parser.parse("feat(): add login").unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, ParseErrorKind::EmptyScope);
+    }
+
+    #[test]
+    fn test_scope_forbidden() {
+        let parser = monorepo_parser();
+        let errors = parser.parse("ci(api): cache cargo").unwrap_err();
+        assert_eq!(
+            errors[0].kind,
+            ParseErrorKind::ScopeForbidden {
+                commit_type: "ci".to_string()
+            }
+        );
+        assert_eq!(errors[0].span, 2..7);
+        assert!(parser.parse("ci: cache cargo").is_ok());
+        assert_eq!(
+            parser.fix_message("ci(api): cache cargo"),
+            "ci: cache cargo"
+        );
+    }
+
+    #[test]
+    fn test_scope_lists_per_type() {
+        let parser = monorepo_parser();
+        assert!(parser.parse("build(deps): bump serde").is_ok());
+        let errors = parser.parse("feat(deps): bump serde").unwrap_err();
+        assert_eq!(
+            errors[0].kind,
+            ParseErrorKind::ScopeNotAllowedForType {
+                found: "deps".to_string(),
+                commit_type: "feat".to_string(),
+                expected: vec!["api".to_string(), "ui".to_string()],
+            }
+        );
+        assert_eq!(errors[0].span, 5..9);
+        // Scopes no type accepts are still invalid scopes
+        let errors = parser.parse("build(db): bump serde").unwrap_err();
+        assert!(matches!(
+            errors[0].kind,
+            ParseErrorKind::InvalidScope { .. }
+        ));
+    }
+
+    #[test]
+    fn test_scope_spans_are_characters() {
+        let parser = ConventionalParser::new(
+            vec!["feat".to_string()],
+            Some(vec!["api".to_string(), "ui".to_string()]),
+        );
+        let errors = parser.parse("feat(ui,  apj): add login").unwrap_err();
+        assert_eq!(errors[0].span, 10..13);
+        assert_eq!(
+            parser.fix_message("feat(ui, apj): add login"),
+            "feat(ui, api): add login"
+        );
+    }
+
+    #[test]
+    fn test_scope_patterns() {
+        let parser = ConventionalParser::new(
+            vec!["feat".to_string()],
+            Some(vec![
+                "pkg-*".to_string(),
+                "api/v*".to_string(),
+                "{web,mobile}-ui".to_string(),
+            ]),
+        );
+        assert!(parser.parse("feat(pkg-core): add login").is_ok());
+        assert!(parser.parse("feat(api/v2, mobile-ui): add login").is_ok());
+        let errors = parser.parse("feat(desktop-ui): add login").unwrap_err();
+        assert!(matches!(
+            errors[0].kind,
+            ParseErrorKind::InvalidScope { .. }
+        ));
+        // Suggestions come from the literal expansions of the patterns
+        assert_eq!(
+            parser.fix_message("feat(mobile-iu): add login"),
+            "feat(mobile-ui): add login"
+        );
+    }
+
+    // ===== ALIAS TESTS =====
+
+    fn alias_parser() -> ConventionalParser {
+        let aliases = |pairs: &[(&str, &str)]| {
+            pairs
+                .iter()
+                .map(|(alias, canonical)| (alias.to_string(), canonical.to_string()))
+                .collect()
+        };
+        ConventionalParser::new(
+            vec!["feat".to_string(), "fix".to_string()],
+            Some(vec!["api".to_string(), "dependencies".to_string()]),
+        )
+        .with_aliases(
+            aliases(&[("feature", "feat"), ("bugfix", "fix")]),
+            aliases(&[("deps", "dependencies")]),
+        )
+    }
+
+    #[test]
+    fn test_type_alias() {
+        let parser = alias_parser();
+        let errors = parser.parse("feature(api): add login").unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].kind,
+            ParseErrorKind::TypeAlias {
+                found: "feature".to_string(),
+                canonical: "feat".to_string(),
+            }
+        );
+        assert_eq!(errors[0].span, 0..7);
+        let suggestion = errors[0].suggestion.as_ref().unwrap();
+        assert_eq!(
+            suggestion.applicability,
+            fix::Applicability::MachineApplicable
+        );
+        assert_eq!(
+            parser.fix_message("bugfix: handle empty input"),
+            "fix: handle empty input"
+        );
+    }
+
+    #[test]
+    fn test_scope_alias() {
+        let parser = alias_parser();
+        let errors = parser.parse("fix(api, deps): bump serde").unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].kind,
+            ParseErrorKind::ScopeAlias {
+                found: "deps".to_string(),
+                canonical: "dependencies".to_string(),
+            }
+        );
+        assert_eq!(errors[0].span, 9..13);
+        assert_eq!(
+            parser.fix_message("feature(deps): bump serde"),
+            "feat(dependencies): bump serde"
+        );
+    }
+
+    #[test]
+    fn test_aliases_are_canonicalized_when_allowed() {
+        let parser = alias_parser().with_rules(BTreeMap::from([
+            ("type-alias".to_string(), Severity::Off),
+            ("scope-alias".to_string(), Severity::Warn),
+        ]));
+        let result = parser.parse("feature(deps): bump serde");
+        assert_eq!(result.warnings().len(), 1);
+        let header = result.unwrap();
+        assert_eq!(header.commit_type, "feat");
+        assert_eq!(header.scope, Some(vec!["dependencies".to_string()]));
+    }
+
+    fn deprecation_parser() -> ConventionalParser {
+        let deprecation = |replacement: Option<&str>, until: Option<&str>| Deprecation {
+            replacement: replacement.map(String::from),
+            until: until.map(String::from),
+        };
+        ConventionalParser::new(
+            vec!["feat".to_string(), "fix".to_string()],
+            Some(vec!["api".to_string(), "ui".to_string()]),
+        )
+        .with_deprecated(DeprecatedConfig {
+            types: [("bugfix".to_string(), deprecation(Some("fix"), None))].into(),
+            scopes: [(
+                "frontend".to_string(),
+                deprecation(Some("ui"), Some("2026-06-30")),
+            )]
+            .into(),
+        })
+        .with_today("2026-06-01")
+    }
+
+    #[test]
+    fn test_deprecated_scope_warns_until_its_date() {
+        let parser = deprecation_parser();
+        let result = parser.parse("feat(api, frontend): add login");
+        let warnings = result.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].kind,
+            ParseErrorKind::DeprecatedScope {
+                found: "frontend".to_string(),
+                replacement: Some("ui".to_string()),
+                until: Some("2026-06-30".to_string()),
+                expired: false,
+            }
+        );
+        assert_eq!(warnings[0].span, 10..18);
+        assert!(result.is_ok());
+
+        let parser = deprecation_parser().with_today("2026-07-01");
+        let errors = parser.parse("feat(frontend): add login").unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            errors[0].kind,
+            ParseErrorKind::DeprecatedScope { expired: true, .. }
+        ));
+    }
+
+    #[test]
+    fn test_deprecated_type() {
+        let parser = deprecation_parser();
+        let result = parser.parse("bugfix: handle empty input");
+        assert_eq!(result.warnings().len(), 1);
+        assert_eq!(result.warnings()[0].span, 0..6);
+        assert_eq!(result.unwrap().commit_type, "bugfix");
+    }
+
+    #[test]
+    fn test_fix_migrates_deprecations() {
+        let parser = deprecation_parser();
+        assert_eq!(
+            parser.fix_message("bugfix(frontend): handle empty input"),
+            "fix(ui): handle empty input"
+        );
+    }
+
+    #[test]
+    fn test_type_case() {
+        let parser = default_parser();
+        let errors = parser.parse("Feat: add login").unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].kind,
+            ParseErrorKind::TypeCase {
+                found: "Feat".to_string(),
+                expected: "feat".to_string(),
+            }
+        );
+        assert_eq!(errors[0].span, 0..4);
+        assert_eq!(parser.fix_message("FIX(api): handle"), "fix(api): handle");
+    }
+
+    #[test]
+    fn test_spec_strict_mode_ignores_type_case() {
+        let parser = default_parser().with_mode(ParserMode::SpecStrict);
+        let header = parser.parse("FEAT(api): add login").unwrap();
+        assert_eq!(header.commit_type, "feat");
+        assert!(parser.parse("feat: add login  ").is_ok());
+        assert!(parser.parse("Feta: add login").is_err());
+
+        // Explicit rules still apply
+        let parser = parser.with_rules(BTreeMap::from([("type-case".to_string(), Severity::Warn)]));
+        let result = parser.parse("Feat: add login");
+        assert_eq!(result.warnings().len(), 1);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_spec_strict_mode_keeps_configured_rules() {
+        let parser = ConventionalParser::new(
+            vec!["feat".to_string()],
+            Some(vec!["api".to_string(), "ui-*".to_string()]),
+        )
+        .with_mode(ParserMode::SpecStrict);
+        assert!(parser.parse("feat(UI-Button): add login").is_ok());
+        let result = parser.parse("feat(API): add login");
+        assert!(result.is_ok(), "{:?}", result.errors());
+        assert!(parser.parse("feat(cli): add login").is_err());
+
+        let parser = parser.with_deprecated(DeprecatedConfig {
+            types: BTreeMap::from([(
+                "feature".to_string(),
+                Deprecation {
+                    replacement: Some("feat".to_string()),
+                    until: Some("2020-01-01".to_string()),
+                },
+            )]),
+            ..DeprecatedConfig::default()
+        });
+        assert!(parser.parse("feature: add login").is_err());
+    }
+
+    #[test]
+    fn test_rule_severity_without_a_diagnostic() {
+        let find = |code| Explanation::find(code).unwrap();
+        let parser = parser_with_rules(&[("invalid-scope", Severity::Warn)]);
+        assert_eq!(parser.rule_severity(find("CP0001")), Severity::Error);
+        assert_eq!(parser.rule_severity(find("CP0002")), Severity::Warn);
+        assert_eq!(parser.rule_severity(find("deprecated-scope")), Severity::Warn);
+
+        let parser = parser_with_rules(&[("CP0006", Severity::Off)]);
+        assert_eq!(parser.rule_severity(find("CP0006")), Severity::Error);
+
+        let parser = default_parser().with_mode(ParserMode::SpecStrict);
+        assert_eq!(parser.rule_severity(find("type-case")), Severity::Off);
+    }
+
+    #[test]
+    fn test_house_style_kinds() {
+        assert!(ParseErrorKind::TrailingSpaces.is_house_style());
+        assert!(
+            ParseErrorKind::TypeCase {
+                found: "Feat".to_string(),
+                expected: "feat".to_string()
+            }
+            .is_house_style()
+        );
+        assert!(!ParseErrorKind::MissingSpace.is_house_style());
+        assert!(!ParseErrorKind::DescriptionTrailingPeriod.is_house_style());
+    }
 }
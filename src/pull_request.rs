@@ -0,0 +1,387 @@
+// Pull request validation with semantic-prs compatible modes
+
+use crate::config::Config;
+use crate::lint::Linter;
+use crate::parser::ParseError;
+
+/// Which parts of a pull request are validated, mirroring semantic-prs options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationMode {
+    /// Either the title or at least one commit must be valid (semantic-prs default)
+    TitleOrCommits,
+    TitleOnly,
+    CommitsOnly,
+    TitleAndCommits,
+}
+
+impl ValidationMode {
+    /// Resolve the mode from config. Like semantic-prs, `titleOnly` wins over
+    /// `commitsOnly`, which wins over `titleAndCommits`.
+    pub fn from_config(config: &Config) -> Self {
+        if config.title_only {
+            Self::TitleOnly
+        } else if config.commits_only {
+            Self::CommitsOnly
+        } else if config.title_and_commits {
+            Self::TitleAndCommits
+        } else {
+            Self::TitleOrCommits
+        }
+    }
+}
+
+/// Validation outcome for a single PR title or commit message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageCheck<'a> {
+    pub input: &'a str,
+    pub errors: Vec<ParseError>,
+    pub warnings: Vec<ParseError>,
+}
+
+impl MessageCheck<'_> {
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PullRequestValidation<'a> {
+    pub mode: ValidationMode,
+    pub title: MessageCheck<'a>,
+    pub commits: Vec<MessageCheck<'a>>,
+    /// Whether the outcome rests on the only non-merge commit alone, as semantic-prs does
+    /// for a single-commit PR without any mode or `anyCommit`
+    pub single_commit: bool,
+    pub valid: bool,
+}
+
+impl PullRequestValidation<'_> {
+    /// Whether the title takes part in the outcome for this mode
+    pub fn checks_title(&self) -> bool {
+        self.mode != ValidationMode::CommitsOnly && !self.single_commit
+    }
+
+    /// Whether the commits take part in the outcome for this mode
+    pub fn checks_commits(&self) -> bool {
+        self.mode != ValidationMode::TitleOnly
+    }
+
+    /// Short explanation of what is needed, in the wording semantic-prs uses
+    pub fn summary(&self) -> &'static str {
+        match (self.valid, self.mode) {
+            (true, ValidationMode::CommitsOnly) => "ready to be merged or rebased",
+            (true, _) if self.title.is_ok() => "ready to be squashed",
+            (true, _) => "ready to be merged or rebased",
+            (false, ValidationMode::TitleOnly) => "add a semantic PR title",
+            (false, ValidationMode::CommitsOnly) => "make sure every commit is semantic",
+            (false, ValidationMode::TitleAndCommits) => {
+                "make sure the PR title and every commit are semantic"
+            }
+            (false, ValidationMode::TitleOrCommits) if self.single_commit => {
+                "make sure the only commit is semantic"
+            }
+            (false, ValidationMode::TitleOrCommits) => "add a semantic commit or PR title",
+        }
+    }
+}
+
+fn is_merge_commit(message: &str) -> bool {
+    message.starts_with("Merge")
+}
+
+fn is_revert_commit(message: &str) -> bool {
+    message.starts_with("Revert")
+}
+
+/// Validate a single title or commit message, honouring `allowMergeCommits`
+/// and `allowRevertCommits`.
+pub fn check_message<'a>(linter: &Linter, config: &Config, input: &'a str) -> MessageCheck<'a> {
+    let allowed = (config.allow_merge_commits && is_merge_commit(input))
+        || (config.allow_revert_commits && is_revert_commit(input));
+
+    if allowed {
+        return MessageCheck {
+            input,
+            errors: Vec::new(),
+            warnings: Vec::new(),
+        };
+    }
+
+    let result = linter.lint(input);
+    MessageCheck {
+        input,
+        errors: result
+            .errors()
+            .map(<[ParseError]>::to_vec)
+            .unwrap_or_default(),
+        warnings: result.warnings().to_vec(),
+    }
+}
+
+/// Validate a pull request title and its commit messages with semantic-prs semantics.
+///
+/// - `titleOnly`: only the title is validated
+/// - `commitsOnly`: only the commits are validated
+/// - `titleAndCommits`: both are validated
+/// - otherwise the title or at least one commit must be valid
+///
+/// With `commitsOnly` or `titleAndCommits`, every commit must be valid unless `anyCommit`
+/// is set, which accepts them if at least one is valid.
+///
+/// Without a mode or `anyCommit`, a PR with a single non-merge commit is judged by that
+/// commit alone: GitHub squash-merges it with the commit message, not the PR title.
+pub fn validate_pull_request<'a>(
+    config: &Config,
+    title: &'a str,
+    commits: &[&'a str],
+) -> PullRequestValidation<'a> {
+    let linter = Linter::from_config(config);
+    let mode = ValidationMode::from_config(config);
+
+    let title = check_message(&linter, config, title);
+    let commits: Vec<_> = commits
+        .iter()
+        .map(|commit| check_message(&linter, config, commit))
+        .collect();
+
+    let title_valid = title.is_ok();
+    let commits_valid = if config.any_commit || mode == ValidationMode::TitleOrCommits {
+        commits.iter().any(MessageCheck::is_ok)
+    } else {
+        commits.iter().all(MessageCheck::is_ok)
+    };
+
+    let non_merge_commits: Vec<_> = commits
+        .iter()
+        .filter(|commit| !is_merge_commit(commit.input))
+        .collect();
+    let single_commit = mode == ValidationMode::TitleOrCommits
+        && !config.any_commit
+        && non_merge_commits.len() == 1;
+
+    let valid = match mode {
+        ValidationMode::TitleOnly => title_valid,
+        ValidationMode::CommitsOnly => commits_valid,
+        ValidationMode::TitleAndCommits => title_valid && commits_valid,
+        ValidationMode::TitleOrCommits if single_commit => non_merge_commits[0].is_ok(),
+        ValidationMode::TitleOrCommits => title_valid || commits_valid,
+    };
+
+    PullRequestValidation {
+        mode,
+        title,
+        commits,
+        single_commit,
+        valid,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Config {
+        Config {
+            types: vec!["feat".to_string(), "fix".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_mode_from_config() {
+        assert_eq!(
+            ValidationMode::from_config(&config()),
+            ValidationMode::TitleOrCommits
+        );
+        let config = Config {
+            title_only: true,
+            commits_only: true,
+            ..config()
+        };
+        assert_eq!(
+            ValidationMode::from_config(&config),
+            ValidationMode::TitleOnly
+        );
+    }
+
+    #[test]
+    fn test_default_mode_accepts_valid_title_with_invalid_commits() {
+        let result = validate_pull_request(&config(), "feat: add api", &["wip", "more wip"]);
+        assert!(result.valid);
+        assert_eq!(result.summary(), "ready to be squashed");
+    }
+
+    #[test]
+    fn test_default_mode_accepts_valid_commits_with_invalid_title() {
+        let result = validate_pull_request(&config(), "Add api", &["feat: add api", "fix: typo"]);
+        assert!(result.valid);
+        assert_eq!(result.summary(), "ready to be merged or rebased");
+    }
+
+    #[test]
+    fn test_default_mode_accepts_one_valid_commit_with_invalid_title() {
+        let result = validate_pull_request(&config(), "Add api", &["wip", "fix: typo"]);
+        assert!(result.valid);
+        assert_eq!(result.summary(), "ready to be merged or rebased");
+    }
+
+    #[test]
+    fn test_default_mode_rejects_when_both_invalid() {
+        let result = validate_pull_request(&config(), "Add api", &["wip", "more wip"]);
+        assert!(!result.valid);
+        assert_eq!(result.summary(), "add a semantic commit or PR title");
+    }
+
+    #[test]
+    fn test_default_mode_single_commit_must_be_valid() {
+        let result = validate_pull_request(&config(), "feat: add api", &["wip"]);
+        assert!(!result.valid);
+        assert_eq!(result.summary(), "make sure the only commit is semantic");
+
+        let result = validate_pull_request(&config(), "feat: add api", &["feat: add api"]);
+        assert!(result.valid);
+    }
+
+    #[test]
+    fn test_default_mode_single_commit_ignores_title() {
+        let result = validate_pull_request(&config(), "Add api", &["feat: add api"]);
+        assert!(result.valid);
+        assert!(result.single_commit);
+        assert!(!result.checks_title());
+        assert_eq!(result.summary(), "ready to be merged or rebased");
+    }
+
+    #[test]
+    fn test_single_commit_rule_ignores_merge_commits() {
+        let commits = ["feat: add api", "Merge branch 'main' into api"];
+        let result = validate_pull_request(&config(), "Add api", &commits);
+        assert!(result.single_commit);
+        assert!(result.valid);
+
+        let config = Config {
+            allow_merge_commits: true,
+            ..config()
+        };
+        let commits = ["wip", "Merge branch 'main' into api"];
+        let result = validate_pull_request(&config, "feat: add api", &commits);
+        assert!(result.single_commit);
+        assert!(!result.valid);
+    }
+
+    #[test]
+    fn test_single_commit_rule_only_applies_without_mode_or_any_commit() {
+        let config = Config {
+            any_commit: true,
+            ..config()
+        };
+        let result = validate_pull_request(&config, "feat: add api", &["wip"]);
+        assert!(!result.single_commit);
+        assert!(result.valid);
+    }
+
+    #[test]
+    fn test_default_mode_without_commits_uses_title() {
+        assert!(validate_pull_request(&config(), "feat: add api", &[]).valid);
+        assert!(!validate_pull_request(&config(), "Add api", &[]).valid);
+    }
+
+    #[test]
+    fn test_title_only_ignores_commits() {
+        let config = Config {
+            title_only: true,
+            ..config()
+        };
+        assert!(validate_pull_request(&config, "feat: add api", &["wip"]).valid);
+        assert!(!validate_pull_request(&config, "Add api", &["feat: add api"]).valid);
+    }
+
+    #[test]
+    fn test_commits_only_ignores_title() {
+        let config = Config {
+            commits_only: true,
+            ..config()
+        };
+        assert!(validate_pull_request(&config, "Add api", &["feat: a", "fix: b"]).valid);
+        assert!(!validate_pull_request(&config, "feat: add api", &["feat: a", "wip"]).valid);
+    }
+
+    #[test]
+    fn test_commits_only_with_any_commit() {
+        let config = Config {
+            commits_only: true,
+            any_commit: true,
+            ..config()
+        };
+        assert!(validate_pull_request(&config, "Add api", &["wip", "fix: b"]).valid);
+        assert!(!validate_pull_request(&config, "Add api", &["wip", "more wip"]).valid);
+    }
+
+    #[test]
+    fn test_title_and_commits_requires_both() {
+        let config = Config {
+            title_and_commits: true,
+            ..config()
+        };
+        assert!(validate_pull_request(&config, "feat: add api", &["feat: a", "fix: b"]).valid);
+        assert!(!validate_pull_request(&config, "Add api", &["feat: a", "fix: b"]).valid);
+        assert!(!validate_pull_request(&config, "feat: add api", &["feat: a", "wip"]).valid);
+    }
+
+    #[test]
+    fn test_merge_commits_require_opt_in() {
+        let commits = ["feat: a", "Merge branch 'main' into feature"];
+        let config = Config {
+            commits_only: true,
+            ..config()
+        };
+        assert!(!validate_pull_request(&config, "Add api", &commits).valid);
+
+        let config = Config {
+            allow_merge_commits: true,
+            ..config
+        };
+        assert!(validate_pull_request(&config, "Add api", &commits).valid);
+    }
+
+    #[test]
+    fn test_revert_commits_require_opt_in() {
+        let commits = ["feat: a", "Revert \"feat: b\""];
+        let config = Config {
+            commits_only: true,
+            ..config()
+        };
+        assert!(!validate_pull_request(&config, "Add api", &commits).valid);
+
+        let config = Config {
+            allow_revert_commits: true,
+            ..config
+        };
+        assert!(validate_pull_request(&config, "Add api", &commits).valid);
+    }
+
+    #[test]
+    fn test_commit_bodies_are_validated() {
+        let config = Config {
+            commits_only: true,
+            ..config()
+        };
+        let result = validate_pull_request(&config, "Add api", &["feat: a\nno blank line"]);
+        assert!(!result.valid);
+        assert!(!result.commits[0].is_ok());
+    }
+
+    #[test]
+    fn test_rules_downgrade_errors_to_warnings() {
+        let mut config = Config {
+            commits_only: true,
+            ..config()
+        };
+        config.rules.insert(
+            "missing-blank-line-after-header".to_string(),
+            crate::config::Severity::Warn,
+        );
+        let result = validate_pull_request(&config, "Add api", &["feat: a\nno blank line"]);
+        assert!(result.valid);
+        assert_eq!(result.commits[0].warnings.len(), 1);
+    }
+}
@@ -1,43 +1,80 @@
-use crate::config::CharSetConfig;
+use crate::config::{CharSetConfig, DescriptionCase};
+use crate::fix;
+use crate::glob;
 use crate::parser::{ParseError, ParseErrorKind};
+use crate::similar::{self, Candidate};
 use ariadne::{CharSet, ColorGenerator, Label, Report, ReportKind, Source};
-use strsim::jaro_winkler;
 
-/// Find the most similar string from a list using Jaro-Winkler similarity
-fn find_similar(target: &str, candidates: &[String]) -> Option<String> {
-    candidates
+/// Ranked "did you mean" candidates for an invalid type or scope. Scope patterns are
+/// compared by their literal expansions.
+pub(crate) fn similar_candidates(kind: &ParseErrorKind) -> Vec<Candidate> {
+    match kind {
+        ParseErrorKind::InvalidType {
+            found, expected, ..
+        } => similar::rank(found, expected, similar::MAX_CANDIDATES),
+        ParseErrorKind::InvalidScope { found, expected }
+        | ParseErrorKind::ScopeNotAllowedForType {
+            found, expected, ..
+        } => similar::rank(found, &glob::literals(expected), similar::MAX_CANDIDATES),
+        _ => Vec::new(),
+    }
+}
+
+/// `'a'`, `'a' or 'b'`, `'a', 'b' or 'c'`
+fn quoted_alternatives(candidates: &[Candidate]) -> String {
+    let quoted: Vec<String> = candidates
         .iter()
-        .map(|candidate| (candidate, jaro_winkler(target, candidate)))
-        .filter(|(_, similarity)| *similarity > 0.8) // High similarity threshold
-        .max_by(|(_, sim1), (_, sim2)| sim1.partial_cmp(sim2).unwrap())
-        .map(|(candidate, _)| candidate.clone())
+        .map(|candidate| format!("'{}'", candidate.value))
+        .collect();
+    match quoted.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} or {last}", rest.join(", ")),
+        _ => quoted.concat(),
+    }
 }
 
-fn get_error_details(kind: &ParseErrorKind) -> (String, String, Option<String>) {
+/// `Did you mean 'a' or 'b'?`, when there is anything to suggest
+fn did_you_mean(kind: &ParseErrorKind) -> Option<String> {
+    let candidates = similar_candidates(kind);
+    (!candidates.is_empty()).then(|| format!("Did you mean {}?", quoted_alternatives(&candidates)))
+}
+
+/// Headline message, span label and optional help text for an error kind
+pub(crate) fn get_error_details(kind: &ParseErrorKind) -> (String, String, Option<String>) {
     match kind {
-        ParseErrorKind::InvalidType { found, expected } => {
+        ParseErrorKind::InvalidType {
+            found,
+            expected,
+            descriptions,
+        } => {
             let msg = format!("Invalid commit type '{found}'");
             let label = format!("'{found}' is not a valid type");
-            let suggestion = find_similar(found, expected);
-            let valid_types = expected.join(", ");
-
-            let help = if let Some(suggestion) = suggestion {
-                format!("Did you mean '{suggestion}'?\nValid types: {valid_types}")
+            let valid_types = if descriptions.is_empty() {
+                format!("Valid types: {}", expected.join(", "))
             } else {
-                format!("Valid types: {valid_types}")
+                // One type per line, with the descriptions aligned
+                let width = expected.iter().map(|t| t.chars().count()).max().unwrap_or(0);
+                expected.iter().fold("Valid types:".to_string(), |list, t| {
+                    match descriptions.get(t) {
+                        Some(description) => format!("{list}\n  {t:<width$}  {description}"),
+                        None => format!("{list}\n  {t}"),
+                    }
+                })
+            };
+
+            let help = match did_you_mean(kind) {
+                Some(did_you_mean) => format!("{did_you_mean}\n{valid_types}"),
+                None => valid_types,
             };
             (msg, label, Some(help))
         }
         ParseErrorKind::InvalidScope { found, expected } => {
             let msg = format!("Invalid scope '{found}'");
             let label = format!("'{found}' is not a valid scope");
-            let suggestion = find_similar(found, expected);
             let valid_scopes = expected.join(", ");
 
-            let help = if let Some(suggestion) = suggestion {
-                format!("Did you mean '{suggestion}'?\nValid scopes: {valid_scopes}")
-            } else {
-                format!("Valid scopes: {valid_scopes}")
+            let help = match did_you_mean(kind) {
+                Some(did_you_mean) => format!("{did_you_mean}\nValid scopes: {valid_scopes}"),
+                None => format!("Valid scopes: {valid_scopes}"),
             };
             (msg, label, Some(help))
         }
@@ -127,27 +164,254 @@ fn get_error_details(kind: &ParseErrorKind) -> (String, String, Option<String>)
             "unexpected space here".to_string(),
             Some("Remove the space immediately before ')'".to_string()),
         ),
+        ParseErrorKind::MissingBlankLineAfterHeader => (
+            "Missing blank line after header".to_string(),
+            "expected an empty line before this".to_string(),
+            Some("Separate the header from the body with a blank line".to_string()),
+        ),
+        ParseErrorKind::MissingTrailerSeparator => (
+            "Malformed trailer".to_string(),
+            "expected 'Token: value' or 'Token #value'".to_string(),
+            Some(
+                "Separate the trailer token from its value with ': ' or ' #', or indent the line to continue the previous trailer"
+                    .to_string(),
+            ),
+        ),
+        ParseErrorKind::TrailerTokenWithSpaces { token } => (
+            format!("Trailer token '{token}' contains whitespace"),
+            "whitespace is not allowed here".to_string(),
+            Some(format!(
+                "Use '-' in place of whitespace, e.g. '{}'",
+                token.split_whitespace().collect::<Vec<_>>().join("-")
+            )),
+        ),
+        ParseErrorKind::DescriptionCase { expected } => match expected {
+            DescriptionCase::Sentence => (
+                "Description must start with an uppercase letter".to_string(),
+                "expected an uppercase letter".to_string(),
+                Some("Capitalize the first word, e.g. 'Add login'".to_string()),
+            ),
+            DescriptionCase::LowerFirst | DescriptionCase::Any => (
+                "Description must start with a lowercase letter".to_string(),
+                "expected a lowercase letter".to_string(),
+                Some("Lowercase the first word, e.g. 'add login'".to_string()),
+            ),
+        },
+        ParseErrorKind::DescriptionTrailingPeriod => (
+            "Description ends with a period".to_string(),
+            "unexpected period".to_string(),
+            Some("Remove the period at the end of the description".to_string()),
+        ),
+        ParseErrorKind::DescriptionTooShort { min, length, unit } => (
+            format!("Description is too short ({length} {})", unit.noun()),
+            format!("expected at least {min} {}", unit.noun()),
+            Some("Describe the change in more detail".to_string()),
+        ),
+        ParseErrorKind::DescriptionTooLong { max, length, unit } => (
+            format!("Description is too long ({length} {})", unit.noun()),
+            format!("longer than {max} {}", unit.noun()),
+            Some("Shorten the description and move details to the commit body".to_string()),
+        ),
+        ParseErrorKind::HeaderTooLong { max, length, unit } => (
+            format!("Header is too long ({length} {})", unit.noun()),
+            format!("longer than {max} {}", unit.noun()),
+            Some("Shorten the header and move details to the commit body".to_string()),
+        ),
+        ParseErrorKind::DescriptionNotImperative { found, imperative } => (
+            "Description is not in the imperative mood".to_string(),
+            format!("'{found}' is not imperative"),
+            Some(format!(
+                "Use '{imperative}': the description completes \"If applied, this commit will ...\""
+            )),
+        ),
+        ParseErrorKind::DescriptionRepeatsType { commit_type } => (
+            format!("Description repeats the type '{commit_type}'"),
+            "repeats the type".to_string(),
+            Some("The type already says what kind of change this is; describe what changed".to_string()),
+        ),
+        ParseErrorKind::ScopeRequired {
+            commit_type,
+            expected,
+        } => {
+            let help = if expected.is_empty() {
+                format!("Add a scope: '{commit_type}(<scope>): ...'")
+            } else {
+                format!(
+                    "Add a scope: '{commit_type}(<scope>): ...'\nValid scopes: {}",
+                    expected.join(", ")
+                )
+            };
+            (
+                format!("Scope required for type '{commit_type}'"),
+                format!("'{commit_type}' needs a scope"),
+                Some(help),
+            )
+        }
+        ParseErrorKind::ScopeForbidden { commit_type } => (
+            format!("Type '{commit_type}' does not take a scope"),
+            "scope not allowed here".to_string(),
+            Some("Remove the scope".to_string()),
+        ),
+        ParseErrorKind::ScopeNotAllowedForType {
+            found,
+            commit_type,
+            expected,
+        } => (
+            format!("Scope '{found}' not allowed with type '{commit_type}'"),
+            format!("'{found}' is not a scope of '{commit_type}'"),
+            Some(format!(
+                "Valid scopes for '{commit_type}': {}",
+                expected.join(", ")
+            )),
+        ),
+        ParseErrorKind::TypeAlias { found, canonical } => (
+            format!("'{found}' is an alias of type '{canonical}'"),
+            format!("use '{canonical}'"),
+            Some(format!("Replace '{found}' with '{canonical}'")),
+        ),
+        ParseErrorKind::ScopeAlias { found, canonical } => (
+            format!("'{found}' is an alias of scope '{canonical}'"),
+            format!("use '{canonical}'"),
+            Some(format!("Replace '{found}' with '{canonical}'")),
+        ),
+        ParseErrorKind::DeprecatedType {
+            found,
+            replacement,
+            until,
+            expired,
+        } => (
+            format!("Type '{found}' is deprecated"),
+            "deprecated type".to_string(),
+            Some(deprecation_help(found, replacement, until, *expired)),
+        ),
+        ParseErrorKind::DeprecatedScope {
+            found,
+            replacement,
+            until,
+            expired,
+        } => (
+            format!("Scope '{found}' is deprecated"),
+            "deprecated scope".to_string(),
+            Some(deprecation_help(found, replacement, until, *expired)),
+        ),
+        ParseErrorKind::TypeCase { found, expected } => (
+            format!("Commit type '{found}' is not written as configured"),
+            format!("write '{expected}'"),
+            Some(format!("Write '{expected}' instead of '{found}'")),
+        ),
+        ParseErrorKind::VagueDescription => (
+            "Description is too vague".to_string(),
+            "says nothing about the change".to_string(),
+            Some("Describe what changed, e.g. 'handle empty config files'".to_string()),
+        ),
+        ParseErrorKind::Custom {
+            message,
+            label,
+            help,
+            ..
+        } => (message.clone(), label.clone(), help.clone()),
+    }
+}
+
+/// Migration hint for a deprecated type or scope
+fn deprecation_help(
+    found: &str,
+    replacement: &Option<String>,
+    until: &Option<String>,
+    expired: bool,
+) -> String {
+    let migrate = match replacement {
+        Some(replacement) => format!("Use '{replacement}' instead of '{found}'"),
+        None => format!("Stop using '{found}'"),
+    };
+    match (until, expired) {
+        (Some(until), false) => format!("{migrate}; it will be rejected after {until}"),
+        (Some(until), true) => format!("{migrate}; it was retired after {until}"),
+        (None, _) => migrate,
+    }
+}
+
+/// 1-based line and column of a character offset
+pub(crate) fn line_column(input: &str, offset: usize) -> (usize, usize) {
+    let mut line = 1;
+    let mut column = 1;
+    for c in input.chars().take(offset) {
+        if c == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+    (line, column)
+}
+
+/// Inline code span that survives backticks in `text`
+fn markdown_code(text: &str) -> String {
+    if text.contains('`') {
+        format!("`` {text} ``")
+    } else {
+        format!("`{text}`")
     }
 }
 
+/// Table cell content: pipes escaped and line breaks kept
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', "<br>")
+}
+
+/// A backtick fence longer than any backtick run in `text`
+fn markdown_fence(text: &str) -> String {
+    let longest = text
+        .split(|c| c != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or_default();
+    "`".repeat(longest.max(2) + 1)
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum OutputFormat {
-    Color, // Colored for terminal
-    Ascii, // Plain ASCII for GitHub Actions
+    Color,    // Colored for terminal
+    Ascii,    // Plain ASCII for GitHub Actions
+    Markdown, // GitHub-flavored Markdown for PR comments and job summaries
+    Compact,  // One gcc-style line per error for editors and screen readers
 }
 
 pub struct ErrorReporter {
     format: OutputFormat,
     charset: CharSetConfig,
+    target_url: Option<String>,
 }
 
 impl ErrorReporter {
     pub fn new(format: OutputFormat, charset: CharSetConfig) -> Self {
-        Self { format, charset }
+        Self {
+            format,
+            charset,
+            target_url: None,
+        }
+    }
+
+    /// Link to the project's commit conventions (`Config::target_url`) in Markdown reports
+    pub fn with_target_url(mut self, target_url: impl Into<String>) -> Self {
+        self.target_url = Some(target_url.into());
+        self
     }
 
     /// Generate error report and return it as a String
     pub fn report_errors(&self, input: &str, errors: &[ParseError]) -> String {
+        self.report_errors_named("input", input, errors)
+    }
+
+    /// Generate error report for a named source (e.g. "title" or a commit SHA)
+    pub fn report_errors_named(&self, name: &str, input: &str, errors: &[ParseError]) -> String {
+        match self.format {
+            OutputFormat::Markdown => return self.render_markdown(name, input, errors),
+            OutputFormat::Compact => return Self::render_compact(name, input, errors),
+            OutputFormat::Color | OutputFormat::Ascii => {}
+        }
+
         let mut output = Vec::new();
 
         // Replace problematic spaces with visible character based on error spans
@@ -160,14 +424,14 @@ impl ErrorReporter {
 
         for group in error_groups {
             let report = if group.len() == 1 {
-                self.build_report(&group[0])
+                self.build_report(name, &group[0])
             } else {
-                self.build_combined_report(&group)
+                self.build_combined_report(name, &group)
             };
 
             // Write to buffer
             report
-                .write(("input", source.clone()), &mut output)
+                .write((name, source.clone()), &mut output)
                 .unwrap_or_else(|e| eprintln!("Failed to write report: {}", e));
         }
 
@@ -178,6 +442,93 @@ impl ErrorReporter {
         self.customize_underlines(rendered, errors)
     }
 
+    /// Render one `name:line:column: error[kind] (code): label` line per error
+    /// (`warning[kind]` for warnings), in the format
+    /// understood by Vim's quickfix list and Emacs' compilation-mode, in input order
+    fn render_compact(name: &str, input: &str, errors: &[ParseError]) -> String {
+        let mut errors: Vec<&ParseError> = errors.iter().collect();
+        errors.sort_by_key(|error| error.span.start);
+
+        let mut output = String::new();
+        for error in errors {
+            let (_, label, _) = get_error_details(&error.kind);
+            let (line, column) = line_column(input, error.span.start);
+            let hint = similar_candidates(&error.kind)
+                .first()
+                .map(|candidate| format!(" (did you mean '{}'?)", candidate.value));
+            let level = if error.is_warning() {
+                "warning"
+            } else {
+                "error"
+            };
+            // Custom rules use their name as their code
+            let code = match error.kind.code() {
+                code if code == error.kind.name() => String::new(),
+                code => format!(" ({code})"),
+            };
+            output.push_str(&format!(
+                "{name}:{line}:{column}: {level}[{}]{code}: {label}{}\n",
+                error.kind.name(),
+                hint.unwrap_or_default()
+            ));
+        }
+        output
+    }
+
+    /// Render a Markdown report: heading, the annotated input in a fenced block, a table
+    /// of errors with their help, a suggested title when fixes apply and a target_url link
+    fn render_markdown(&self, name: &str, input: &str, errors: &[ParseError]) -> String {
+        let annotated = ErrorReporter::new(OutputFormat::Ascii, self.charset)
+            .report_errors_named(name, input, errors);
+        let annotated = annotated.trim_end();
+        let fence = markdown_fence(annotated);
+
+        let heading = if errors.iter().all(ParseError::is_warning) {
+            "Conventional commit warnings"
+        } else {
+            "Invalid conventional commit"
+        };
+        let mut output = if name == "input" {
+            format!("## {heading}\n\n")
+        } else {
+            format!("## {heading} in {}\n\n", markdown_code(name))
+        };
+        output.push_str(&format!("{fence}text\n{annotated}\n{fence}\n\n"));
+
+        output.push_str("| Code | Error | Help |\n| --- | --- | --- |\n");
+        for error in errors {
+            let (message, _, help) = get_error_details(&error.kind);
+            let message = if error.is_warning() {
+                format!("Warning: {message}")
+            } else {
+                message
+            };
+            output.push_str(&format!(
+                "| `{}` | {} | {} |\n",
+                error.kind.code(),
+                markdown_cell(&message),
+                markdown_cell(help.as_deref().unwrap_or(""))
+            ));
+        }
+
+        let fixed = fix::apply_suggestions(input, errors);
+        let title = |text: &str| text.lines().next().unwrap_or_default().to_string();
+        if title(&fixed) != title(input) {
+            output.push_str(&format!(
+                "\n**Suggested title:** {}\n",
+                markdown_code(&title(&fixed))
+            ));
+        }
+
+        if let Some(url) = &self.target_url {
+            output.push_str(&format!(
+                "\nSee [the commit message conventions]({url}) for details.\n"
+            ));
+        }
+
+        output
+    }
+
     /// Customize underline characters to distinguish ranges from single points
     fn customize_underlines(&self, output: String, _errors: &[ParseError]) -> String {
         if self.charset == CharSetConfig::Ascii {
@@ -350,28 +701,31 @@ impl ErrorReporter {
         chars.iter().collect()
     }
 
-    fn build_report(
+    fn build_report<'a>(
         &self,
+        name: &'a str,
         error: &ParseError,
-    ) -> Report<'static, (&'static str, std::ops::Range<usize>)> {
+    ) -> Report<'a, (&'a str, std::ops::Range<usize>)> {
         let mut colors = ColorGenerator::new();
-        let error_color = if self.format == OutputFormat::Ascii {
-            None
-        } else {
+        let error_color = if self.format == OutputFormat::Color {
             Some(colors.next())
+        } else {
+            None
         };
 
         let (message, label_text, help_text) = get_error_details(&error.kind);
 
-        let mut label = Label::new(("input", error.span.clone())).with_message(label_text);
+        let mut label = Label::new((name, error.span.clone())).with_message(label_text);
 
         if let Some(color) = error_color {
             label = label.with_color(color);
         }
 
-        let mut report_builder = Report::build(ReportKind::Error, ("input", error.span.clone()))
-            .with_message(&message)
-            .with_label(label);
+        let mut report_builder =
+            Report::build(Self::report_kind(error), (name, error.span.clone()))
+                .with_code(error.kind.code())
+                .with_message(&message)
+                .with_label(label);
 
         if let Some(help) = help_text {
             report_builder = report_builder.with_help(help);
@@ -380,21 +734,31 @@ impl ErrorReporter {
         self.apply_report_config(report_builder).finish()
     }
 
-    /// Group related errors that should be displayed together
-    fn group_errors(&self, errors: &[ParseError]) -> Vec<Vec<ParseError>> {
-        // Group all errors into a single report for less verbosity
-        if errors.is_empty() {
-            vec![]
+    fn report_kind(error: &ParseError) -> ReportKind<'static> {
+        if error.is_warning() {
+            ReportKind::Warning
         } else {
-            vec![errors.to_vec()]
+            ReportKind::Error
         }
     }
 
+    /// Group related errors that should be displayed together
+    fn group_errors(&self, errors: &[ParseError]) -> Vec<Vec<ParseError>> {
+        // One report for all errors and one for all warnings, for less verbosity
+        let (warnings, errors): (Vec<_>, Vec<_>) =
+            errors.iter().cloned().partition(ParseError::is_warning);
+        [errors, warnings]
+            .into_iter()
+            .filter(|group| !group.is_empty())
+            .collect()
+    }
+
     /// Build a combined report for multiple related errors
-    fn build_combined_report(
+    fn build_combined_report<'a>(
         &self,
+        name: &'a str,
         errors: &[ParseError],
-    ) -> Report<'static, (&'static str, std::ops::Range<usize>)> {
+    ) -> Report<'a, (&'a str, std::ops::Range<usize>)> {
         let mut colors = ColorGenerator::new();
 
         // Use the first error's span as the main report span
@@ -404,25 +768,35 @@ impl ErrorReporter {
         let message = if errors.len() == 1 {
             let (msg, _, _) = get_error_details(&errors[0].kind);
             msg
+        } else if errors[0].is_warning() {
+            "Commit message format warnings".to_string()
         } else {
             "Invalid commit message format".to_string()
         };
 
-        let mut report_builder =
-            Report::build(ReportKind::Error, ("input", main_span)).with_message(message);
+        let mut codes: Vec<&str> = Vec::new();
+        for error in errors {
+            if !codes.contains(&error.kind.code()) {
+                codes.push(error.kind.code());
+            }
+        }
+
+        let mut report_builder = Report::build(Self::report_kind(&errors[0]), (name, main_span))
+            .with_code(codes.join(", "))
+            .with_message(message);
 
         // Add a label for each error
         for (idx, error) in errors.iter().enumerate() {
-            let error_color = if self.format == OutputFormat::Ascii {
-                None
-            } else {
+            let error_color = if self.format == OutputFormat::Color {
                 Some(colors.next())
+            } else {
+                None
             };
 
             let (_msg, label_text, help_text) = get_error_details(&error.kind);
             let label_with_num = format!("{label_text} (#{num})", num = idx + 1);
 
-            let mut label = Label::new(("input", error.span.clone())).with_message(label_with_num);
+            let mut label = Label::new((name, error.span.clone())).with_message(label_with_num);
 
             if let Some(color) = error_color {
                 label = label.with_color(color);
@@ -442,7 +816,7 @@ impl ErrorReporter {
         &self,
         report_builder: ariadne::ReportBuilder<'a, (&'a str, std::ops::Range<usize>)>,
     ) -> ariadne::ReportBuilder<'a, (&'a str, std::ops::Range<usize>)> {
-        if self.format == OutputFormat::Ascii {
+        if self.format != OutputFormat::Color {
             report_builder.with_config(
                 ariadne::Config::default()
                     .with_color(false)
@@ -454,11 +828,11 @@ impl ErrorReporter {
         }
     }
 
-    /// Print errors to stderr (for terminal) or stdout (for GitHub)
+    /// Print errors to stderr (for terminal) or stdout (for GitHub and Markdown)
     pub fn print_errors(&self, input: &str, errors: &[ParseError]) {
         let report = self.report_errors(input, errors);
 
-        if self.format == OutputFormat::Ascii {
+        if self.format != OutputFormat::Color {
             // Print to stdout for GitHub Actions to capture
             print!("{}", report);
         } else {
@@ -480,6 +854,7 @@ mod tests {
             ParseErrorKind::InvalidType {
                 found: "fature".to_string(),
                 expected: vec!["feat".to_string(), "fix".to_string()],
+                descriptions: std::collections::BTreeMap::new(),
             },
             0..6,
         );
@@ -491,6 +866,39 @@ mod tests {
         assert!(report.contains("fature"));
     }
 
+    #[test]
+    fn test_help_lists_ranked_candidates() {
+        let kind = ParseErrorKind::InvalidScope {
+            found: "ap".to_string(),
+            expected: vec!["app".to_string(), "api".to_string(), "docs".to_string()],
+        };
+        let (_, _, help) = get_error_details(&kind);
+        assert_eq!(
+            help.unwrap(),
+            "Did you mean 'app' or 'api'?\nValid scopes: app, api, docs"
+        );
+    }
+
+    #[test]
+    fn test_invalid_type_help_lists_descriptions() {
+        let kind = ParseErrorKind::InvalidType {
+            found: "feta".to_string(),
+            expected: vec!["feat".to_string(), "chore".to_string(), "ci".to_string()],
+            descriptions: [
+                ("feat", "A new feature"),
+                ("chore", "Other changes that don't modify src or test files"),
+            ]
+            .into_iter()
+            .map(|(name, description)| (name.to_string(), description.to_string()))
+            .collect(),
+        };
+        let (_, _, help) = get_error_details(&kind);
+        assert_eq!(
+            help.unwrap(),
+            "Did you mean 'feat'?\nValid types:\n  feat   A new feature\n  chore  Other changes that don't modify src or test files\n  ci"
+        );
+    }
+
     #[test]
     fn test_report_invalid_scope() {
         let reporter = ErrorReporter::new(OutputFormat::Color, CharSetConfig::Unicode);
@@ -554,6 +962,91 @@ mod tests {
         assert!(report.contains("feat__"));
     }
 
+    #[test]
+    fn test_markdown_report() {
+        let reporter = ErrorReporter::new(OutputFormat::Markdown, CharSetConfig::Ascii)
+            .with_target_url("https://example.com/conventions");
+        let error = ParseError::new(
+            ParseErrorKind::InvalidType {
+                found: "feta".to_string(),
+                expected: vec!["feat".to_string(), "fix".to_string()],
+                descriptions: std::collections::BTreeMap::new(),
+            },
+            0..4,
+        );
+        let error = crate::fix::suggest("feta: a | b", &error)
+            .map_or(error.clone(), |suggestion| {
+                error.with_suggestion(suggestion)
+            });
+        let report = reporter.report_errors_named("title", "feta: a | b", &[error]);
+
+        assert!(report.starts_with("## Invalid conventional commit in `title`\n"));
+        assert!(report.contains("```text\n[CP0001] Error: Invalid commit type 'feta'"));
+        assert!(!report.contains("\x1b["));
+        assert!(report.contains(
+            "| `CP0001` | Invalid commit type 'feta' | Did you mean 'feat'?<br>Valid types: feat, fix |"
+        ));
+        assert!(report.contains("**Suggested title:** `feat: a | b`"));
+        assert!(report.contains("(https://example.com/conventions)"));
+    }
+
+    #[test]
+    fn test_compact_report() {
+        let reporter = ErrorReporter::new(OutputFormat::Compact, CharSetConfig::Unicode);
+        let errors = [
+            ParseError::new(
+                ParseErrorKind::InvalidType {
+                    found: "feta".to_string(),
+                    expected: vec!["feat".to_string(), "fix".to_string()],
+                    descriptions: std::collections::BTreeMap::new(),
+                },
+                0..4,
+            ),
+            ParseError::new(ParseErrorKind::MissingBlankLineAfterHeader, 8..12),
+        ];
+        let report = reporter.report_errors("feta: a\nbody", &errors);
+
+        assert_eq!(
+            report,
+            "input:1:1: error[invalid-type] (CP0001): 'feta' is not a valid type (did you mean 'feat'?)\n\
+             input:2:1: error[missing-blank-line-after-header] (CP0018): expected an empty line before this\n"
+        );
+    }
+
+    #[test]
+    fn test_warnings_are_reported_separately() {
+        let reporter = ErrorReporter::new(OutputFormat::Ascii, CharSetConfig::Ascii);
+        let mut warning = ParseError::new(ParseErrorKind::TrailingSpaces, 7..9);
+        warning.severity = crate::config::Severity::Warn;
+        let errors = [
+            ParseError::new(ParseErrorKind::ExtraSpaceBeforeColon, 4..5),
+            warning.clone(),
+        ];
+        let report = reporter.report_errors("feat : a  ", &errors);
+        assert!(report.contains("[CP0011] Error: Extra space found"));
+        assert!(report.contains("[CP0015] Warning: Trailing spaces"));
+
+        let compact = ErrorReporter::new(OutputFormat::Compact, CharSetConfig::Ascii)
+            .report_errors("feat: a  ", std::slice::from_ref(&warning));
+        assert!(
+            compact
+                .starts_with("input:1:8: warning[trailing-spaces] (CP0015): trailing whitespace")
+        );
+
+        let markdown = ErrorReporter::new(OutputFormat::Markdown, CharSetConfig::Ascii)
+            .report_errors("feat: a  ", &[warning]);
+        assert!(markdown.starts_with("## Conventional commit warnings\n"));
+        assert!(markdown.contains("| `CP0015` | Warning: Trailing spaces"));
+    }
+
+    #[test]
+    fn test_markdown_helpers() {
+        assert_eq!(markdown_code("a`b"), "`` a`b ``");
+        assert_eq!(markdown_cell("a|b\nc"), "a\\|b<br>c");
+        assert_eq!(markdown_fence("```"), "````");
+        assert_eq!(markdown_fence("x"), "```");
+    }
+
     #[test]
     fn test_unicode_charset_uses_visible_space_char() {
         let reporter = ErrorReporter::new(OutputFormat::Ascii, CharSetConfig::Unicode);
@@ -0,0 +1,461 @@
+// SARIF 2.1.0 output for code-scanning dashboards
+
+use crate::config::Severity;
+use crate::explain::{EXPLANATIONS, Explanation};
+use crate::parser::{ConventionalParser, ParseError};
+use crate::report::{get_error_details, line_column};
+use serde::Serialize;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const INFORMATION_URI: &str = "https://github.com/scarf005/conventional-prs";
+
+/// A validated commit message or title and the errors and warnings found in it
+#[derive(Debug, Clone, PartialEq)]
+pub struct SarifArtifact<'a> {
+    /// Artifact URI, e.g. a commit message file path or a commit SHA
+    pub uri: String,
+    pub input: &'a str,
+    /// Errors and warnings
+    pub errors: Vec<ParseError>,
+}
+
+impl<'a> SarifArtifact<'a> {
+    /// Spaces in `uri` (e.g. `commit 1`) are percent-encoded to keep it a valid URI reference
+    pub fn new(uri: impl Into<String>, input: &'a str, errors: Vec<ParseError>) -> Self {
+        Self {
+            uri: uri.into().replace(' ', "%20"),
+            input,
+            errors,
+        }
+    }
+}
+
+/// SARIF 2.1.0 log with a single run
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SarifLog {
+    #[serde(rename = "$schema")]
+    pub schema: &'static str,
+    pub version: &'static str,
+    pub runs: Vec<SarifRun>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRun {
+    pub tool: SarifTool,
+    /// Always `unicodeCodePoints`, matching the character spans of [`ParseError`]
+    pub column_kind: &'static str,
+    pub artifacts: Vec<SarifFile>,
+    pub results: Vec<SarifResult>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SarifTool {
+    pub driver: SarifDriver,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifDriver {
+    pub name: &'static str,
+    pub version: &'static str,
+    pub information_uri: &'static str,
+    pub rules: Vec<SarifRule>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRule {
+    /// Error code, e.g. `CP0001`
+    pub id: String,
+    /// Kind name, e.g. `invalid-type`
+    pub name: String,
+    pub short_description: SarifText,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_description: Option<SarifText>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub help: Option<SarifText>,
+    pub help_uri: String,
+    pub default_configuration: SarifConfiguration,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SarifConfiguration {
+    pub level: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SarifText {
+    pub text: String,
+}
+
+impl SarifText {
+    fn new(text: impl Into<String>) -> Self {
+        Self { text: text.into() }
+    }
+}
+
+/// A validated message, with its text embedded in the log
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SarifFile {
+    pub location: SarifArtifactLocation,
+    pub contents: SarifText,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SarifArtifactLocation {
+    pub uri: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+    pub rule_id: String,
+    /// Index of the rule in `tool.driver.rules`
+    pub rule_index: usize,
+    pub level: &'static str,
+    pub message: SarifText,
+    pub locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fixes: Vec<SarifFix>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifLocation {
+    pub physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifPhysicalLocation {
+    pub artifact_location: SarifArtifactLocation,
+    pub region: SarifRegion,
+}
+
+/// SARIF region for a character span. Columns count code points, as declared by the run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRegion {
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+    pub char_offset: usize,
+    pub char_length: usize,
+}
+
+impl SarifRegion {
+    fn new(input: &str, span: &std::ops::Range<usize>) -> Self {
+        let (start_line, start_column) = line_column(input, span.start);
+        let (end_line, end_column) = line_column(input, span.end);
+        Self {
+            start_line,
+            start_column,
+            end_line,
+            end_column,
+            char_offset: span.start,
+            char_length: span.end - span.start,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifFix {
+    pub artifact_changes: Vec<SarifArtifactChange>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifArtifactChange {
+    pub artifact_location: SarifArtifactLocation,
+    pub replacements: Vec<SarifReplacement>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifReplacement {
+    pub deleted_region: SarifRegion,
+    pub inserted_content: SarifText,
+}
+
+/// SARIF level of a severity
+fn sarif_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warn => "warning",
+        Severity::Off => "none",
+    }
+}
+
+/// Rule for a built-in error code, linking to its section of `docs/rules.md`
+fn builtin_rule(parser: &ConventionalParser, explanation: &Explanation) -> SarifRule {
+    SarifRule {
+        id: explanation.code.to_string(),
+        name: explanation.name.to_string(),
+        short_description: SarifText::new(explanation.summary),
+        full_description: Some(SarifText::new(explanation.description)),
+        help: Some(SarifText::new(explanation.render())),
+        help_uri: explanation.help_uri(),
+        default_configuration: SarifConfiguration {
+            level: sarif_level(parser.rule_severity(explanation)),
+        },
+    }
+}
+
+/// Build a SARIF 2.1.0 log. `tool.driver.rules` lists every built-in error code in code
+/// order, so rule indexes are stable, followed by the custom rules that reported something.
+/// Default levels are the severities configured in `parser`. Custom rules have no docs of
+/// their own and link to `help_uri` (usually `Config::target_url`).
+pub fn sarif_log(
+    parser: &ConventionalParser,
+    help_uri: &str,
+    artifacts: &[SarifArtifact],
+) -> SarifLog {
+    let mut rules: Vec<SarifRule> = EXPLANATIONS
+        .iter()
+        .map(|explanation| builtin_rule(parser, explanation))
+        .collect();
+    let mut results = Vec::new();
+
+    for (artifact_index, artifact) in artifacts.iter().enumerate() {
+        let location = || SarifArtifactLocation {
+            uri: artifact.uri.clone(),
+            index: Some(artifact_index),
+        };
+
+        for error in &artifact.errors {
+            let rule_id = error.kind.code();
+            let rule_index = match rules.iter().position(|rule| rule.id == rule_id) {
+                Some(idx) => idx,
+                None => {
+                    rules.push(SarifRule {
+                        id: rule_id.to_string(),
+                        name: rule_id.to_string(),
+                        short_description: SarifText::new(get_error_details(&error.kind).0),
+                        full_description: None,
+                        help: None,
+                        help_uri: help_uri.to_string(),
+                        default_configuration: SarifConfiguration {
+                            level: sarif_level(parser.severity(&error.kind)),
+                        },
+                    });
+                    rules.len() - 1
+                }
+            };
+
+            let (message, _, help) = get_error_details(&error.kind);
+            let text = match help {
+                Some(help) => format!("{message}\n{help}"),
+                None => message,
+            };
+
+            let fixes = error
+                .suggestion
+                .iter()
+                .map(|suggestion| SarifFix {
+                    artifact_changes: vec![SarifArtifactChange {
+                        artifact_location: location(),
+                        replacements: vec![SarifReplacement {
+                            deleted_region: SarifRegion::new(artifact.input, &suggestion.span),
+                            inserted_content: SarifText::new(suggestion.replacement.clone()),
+                        }],
+                    }],
+                })
+                .collect();
+
+            results.push(SarifResult {
+                rule_id: rule_id.to_string(),
+                rule_index,
+                level: sarif_level(error.severity),
+                message: SarifText::new(text),
+                locations: vec![SarifLocation {
+                    physical_location: SarifPhysicalLocation {
+                        artifact_location: location(),
+                        region: SarifRegion::new(artifact.input, &error.span),
+                    },
+                }],
+                fixes,
+            });
+        }
+    }
+
+    let artifacts = artifacts
+        .iter()
+        .map(|artifact| SarifFile {
+            location: SarifArtifactLocation {
+                uri: artifact.uri.clone(),
+                index: None,
+            },
+            contents: SarifText::new(artifact.input),
+        })
+        .collect();
+
+    SarifLog {
+        schema: SARIF_SCHEMA,
+        version: "2.1.0",
+        runs: vec![SarifRun {
+            tool: SarifTool {
+                driver: SarifDriver {
+                    name: env!("CARGO_PKG_NAME"),
+                    version: env!("CARGO_PKG_VERSION"),
+                    information_uri: INFORMATION_URI,
+                    rules,
+                },
+            },
+            column_kind: "unicodeCodePoints",
+            artifacts,
+            results,
+        }],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    use std::collections::BTreeMap;
+
+    fn parser() -> ConventionalParser {
+        ConventionalParser::new(vec!["feat".to_string(), "fix".to_string()], None)
+    }
+
+    fn errors(input: &str) -> Vec<ParseError> {
+        parser()
+            .parse_message(input)
+            .errors()
+            .map(<[ParseError]>::to_vec)
+            .unwrap_or_default()
+    }
+
+    fn log(parser: &ConventionalParser, artifacts: &[SarifArtifact]) -> Value {
+        serde_json::to_value(sarif_log(parser, "https://example.com", artifacts)).unwrap()
+    }
+
+    #[test]
+    fn test_rules_list_every_code() {
+        let first = "feta: a";
+        let second = "fxi: b";
+        let log = log(
+            &parser(),
+            &[
+                SarifArtifact::new("commit/1", first, errors(first)),
+                SarifArtifact::new("commit/2", second, errors(second)),
+            ],
+        );
+
+        let run = &log["runs"][0];
+        assert_eq!(log["version"], "2.1.0");
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), EXPLANATIONS.len());
+        for (idx, explanation) in EXPLANATIONS.iter().enumerate() {
+            assert_eq!(rules[idx]["id"], explanation.code);
+            assert_eq!(rules[idx]["name"], explanation.name);
+            assert_eq!(rules[idx]["helpUri"], explanation.help_uri());
+        }
+        assert_eq!(run["results"].as_array().unwrap().len(), 2);
+        assert_eq!(run["results"][0]["ruleId"], "CP0001");
+        assert_eq!(run["results"][0]["ruleIndex"], 0);
+        assert_eq!(run["results"][1]["ruleIndex"], 0);
+        assert_eq!(run["artifacts"][1]["contents"]["text"], second);
+    }
+
+    #[test]
+    fn test_region_uses_lines_and_code_point_columns() {
+        let input = "feat: é\nbody";
+        let log = log(
+            &parser(),
+            &[SarifArtifact::new("message", input, errors(input))],
+        );
+
+        let result = &log["runs"][0]["results"][0];
+        assert_eq!(result["ruleId"], "CP0018");
+        assert_eq!(result["ruleIndex"], 17);
+        let region = &result["locations"][0]["physicalLocation"]["region"];
+        assert_eq!(region["startLine"], 2);
+        assert_eq!(region["startColumn"], 1);
+        assert_eq!(region["endColumn"], 5);
+        assert_eq!(region["charOffset"], 8);
+        assert_eq!(region["charLength"], 4);
+        assert_eq!(
+            result["fixes"][0]["artifactChanges"][0]["replacements"][0]["insertedContent"]["text"],
+            "\n"
+        );
+    }
+
+    #[test]
+    fn test_warning_level() {
+        let parser = parser().with_rules(BTreeMap::from([(
+            "trailing-spaces".to_string(),
+            Severity::Warn,
+        )]));
+        let input = "feat: a  ";
+        let warnings = parser.parse_message(input).warnings().to_vec();
+        let log = log(&parser, &[SarifArtifact::new("message", input, warnings)]);
+        let run = &log["runs"][0];
+        assert_eq!(run["results"][0]["level"], "warning");
+        let rule = &run["tool"]["driver"]["rules"][run["results"][0]["ruleIndex"]
+            .as_u64()
+            .unwrap() as usize];
+        assert_eq!(rule["name"], "trailing-spaces");
+        assert_eq!(rule["defaultConfiguration"]["level"], "warning");
+    }
+
+    #[test]
+    fn test_default_level_is_the_configured_severity() {
+        // A diagnostic downgraded by hand still reports the rule's configured level
+        let input = "feat: a  ";
+        let mut warnings = errors(input);
+        warnings[0].severity = Severity::Warn;
+        let parser = parser().with_rules(BTreeMap::from([(
+            "CP0020".to_string(),
+            Severity::Off,
+        )]));
+        let log = log(&parser, &[SarifArtifact::new("message", input, warnings)]);
+        let run = &log["runs"][0];
+        let rules = &run["tool"]["driver"]["rules"];
+        assert_eq!(run["results"][0]["level"], "warning");
+        assert_eq!(rules[14]["name"], "trailing-spaces");
+        assert_eq!(rules[14]["defaultConfiguration"]["level"], "error");
+        assert_eq!(rules[19]["defaultConfiguration"]["level"], "none");
+        assert_eq!(rules[33]["name"], "deprecated-type");
+        assert_eq!(rules[33]["defaultConfiguration"]["level"], "warning");
+    }
+
+    #[test]
+    fn test_custom_rules_follow_the_built_in_ones() {
+        let input = "feat: a";
+        let error = ParseError::custom("no-a", 6..7, "Says 'a'", "here");
+        let log = log(
+            &parser(),
+            &[SarifArtifact::new("message", input, vec![error.clone(), error])],
+        );
+        let run = &log["runs"][0];
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), EXPLANATIONS.len() + 1);
+        assert_eq!(rules[EXPLANATIONS.len()]["id"], "no-a");
+        assert_eq!(rules[EXPLANATIONS.len()]["helpUri"], "https://example.com");
+        assert_eq!(run["results"][1]["ruleIndex"], EXPLANATIONS.len());
+    }
+
+    #[test]
+    fn test_valid_input_has_no_results() {
+        let input = "feat: a";
+        let log = log(
+            &parser(),
+            &[SarifArtifact::new("message", input, errors(input))],
+        );
+        assert!(log["runs"][0]["results"].as_array().unwrap().is_empty());
+        assert!(log["runs"][0]["results"][0]["fixes"].is_null());
+        assert_eq!(
+            log["runs"][0]["tool"]["driver"]["rules"]
+                .as_array()
+                .unwrap()
+                .len(),
+            EXPLANATIONS.len()
+        );
+    }
+}
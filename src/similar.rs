@@ -0,0 +1,202 @@
+// "Did you mean" ranking: typo distance, keyboard adjacency, prefixes and abbreviations
+
+/// Most candidates returned by [`rank`] for diagnostics
+pub const MAX_CANDIDATES: usize = 3;
+
+/// Candidates scoring below this are not worth suggesting
+const MIN_CONFIDENCE: f64 = 0.6;
+
+/// A suggested value and how confident the match is, from 0 to 1
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candidate {
+    pub value: String,
+    pub confidence: f64,
+}
+
+/// QWERTY rows; the row below is offset half a key to the right
+const KEYBOARD: [&str; 4] = ["1234567890", "qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+fn key_position(c: char) -> Option<(usize, usize)> {
+    KEYBOARD
+        .iter()
+        .enumerate()
+        .find_map(|(row, keys)| keys.chars().position(|key| key == c).map(|col| (row, col)))
+}
+
+/// Whether two keys touch on a QWERTY keyboard
+fn adjacent_keys(a: char, b: char) -> bool {
+    let (Some((row_a, col_a)), Some((row_b, col_b))) = (key_position(a), key_position(b)) else {
+        return false;
+    };
+    match (
+        row_a as isize - row_b as isize,
+        col_a as isize - col_b as isize,
+    ) {
+        (0, -1 | 1) => true,
+        // A key touches the key above it and the one to the right of that
+        (1, 0 | -1) | (-1, 0 | 1) => true,
+        _ => false,
+    }
+}
+
+/// Damerau-Levenshtein distance (optimal string alignment). Slips of the finger cost
+/// half an edit: swapping two characters or hitting a neighbouring key.
+fn distance(a: &[char], b: &[char]) -> f64 {
+    let mut rows = vec![vec![0.0; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i as f64;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j as f64;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let substitution = match (a[i - 1], b[j - 1]) {
+                (x, y) if x == y => 0.0,
+                (x, y) if adjacent_keys(x, y) => 0.5,
+                _ => 1.0,
+            };
+            let mut cost = (rows[i - 1][j] + 1.0)
+                .min(rows[i][j - 1] + 1.0)
+                .min(rows[i - 1][j - 1] + substitution);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                cost = cost.min(rows[i - 2][j - 2] + 0.5);
+            }
+            rows[i][j] = cost;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+/// Whether the characters of `short` appear in order in `long`
+fn is_subsequence(short: &[char], long: &[char]) -> bool {
+    let mut long = long.iter();
+    short.iter().all(|c| long.any(|l| l == c))
+}
+
+/// How likely `candidate` is what was meant by `target`, from 0 to 1
+pub fn confidence(target: &str, candidate: &str) -> f64 {
+    if target == candidate {
+        return 1.0;
+    }
+    let typed: Vec<char> = target.to_lowercase().chars().collect();
+    let known: Vec<char> = candidate.to_lowercase().chars().collect();
+    if typed.is_empty() || known.is_empty() {
+        return 0.0;
+    }
+    if typed == known {
+        // Only the case differs
+        return 0.95;
+    }
+
+    let longest = typed.len().max(known.len()) as f64;
+    let edit = 1.0 - distance(&typed, &known) / longest;
+    let coverage = typed.len() as f64 / known.len() as f64;
+    // `refac` for `refactor`
+    let prefix = if typed.len() >= 2 && known.starts_with(&typed) {
+        0.65 + 0.3 * coverage
+    } else {
+        0.0
+    };
+    // `ft` for `feat`
+    let abbreviation = if typed.len() >= 2 && typed[0] == known[0] && is_subsequence(&typed, &known)
+    {
+        0.6 + 0.3 * coverage
+    } else {
+        0.0
+    };
+    edit.max(prefix).max(abbreviation)
+}
+
+/// Up to `limit` candidates close enough to `target`, best first. Ties keep the order
+/// of `candidates`.
+pub fn rank(target: &str, candidates: &[String], limit: usize) -> Vec<Candidate> {
+    let mut ranked: Vec<Candidate> = Vec::new();
+    for candidate in candidates {
+        if ranked.iter().any(|ranked| &ranked.value == candidate) {
+            continue;
+        }
+        let confidence = confidence(target, candidate);
+        if confidence >= MIN_CONFIDENCE {
+            ranked.push(Candidate {
+                value: candidate.clone(),
+                confidence,
+            });
+        }
+    }
+    ranked.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+    ranked.truncate(limit);
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn types() -> Vec<String> {
+        ["feat", "fix", "docs", "refactor", "perf", "chore", "ci"]
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+
+    fn best(target: &str) -> Option<String> {
+        rank(target, &types(), MAX_CANDIDATES)
+            .into_iter()
+            .next()
+            .map(|candidate| candidate.value)
+    }
+
+    #[test]
+    fn test_typos() {
+        assert_eq!(best("fxi").as_deref(), Some("fix"));
+        assert_eq!(best("feta").as_deref(), Some("feat"));
+        assert_eq!(best("chroe").as_deref(), Some("chore"));
+        assert_eq!(best("dcos").as_deref(), Some("docs"));
+        assert_eq!(best("featt").as_deref(), Some("feat"));
+    }
+
+    #[test]
+    fn test_keyboard_neighbours_are_cheaper() {
+        assert!(adjacent_keys('x', 'z'));
+        assert!(adjacent_keys('f', 'r'));
+        assert!(adjacent_keys('f', 'c'));
+        assert!(!adjacent_keys('f', 'p'));
+        assert!(confidence("fiz", "fix") > confidence("fip", "fix"));
+    }
+
+    #[test]
+    fn test_prefixes_and_abbreviations() {
+        assert_eq!(best("refac").as_deref(), Some("refactor"));
+        assert_eq!(best("ft").as_deref(), Some("feat"));
+        assert_eq!(best("doc").as_deref(), Some("docs"));
+        assert_eq!(best("f"), None);
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        assert_eq!(best("FEAT").as_deref(), Some("feat"));
+        assert_eq!(confidence("Fix", "fix"), 0.95);
+        assert_eq!(confidence("fix", "fix"), 1.0);
+    }
+
+    #[test]
+    fn test_rank() {
+        let ranked = rank("fe", &types(), MAX_CANDIDATES);
+        assert_eq!(ranked[0].value, "feat");
+        assert!(
+            ranked
+                .windows(2)
+                .all(|w| w[0].confidence >= w[1].confidence)
+        );
+
+        let ranked = rank("perff", &types(), 1);
+        assert_eq!(ranked.len(), 1);
+        assert_eq!(ranked[0].value, "perf");
+
+        assert!(rank("xyzzy", &types(), MAX_CANDIDATES).is_empty());
+
+        let duplicates = vec!["ui".to_string(), "ui".to_string()];
+        assert_eq!(rank("iu", &duplicates, MAX_CANDIDATES).len(), 1);
+    }
+}
@@ -0,0 +1,537 @@
+// Built-in style rules for the description and header, configured in `Config`
+
+use crate::config::{Config, DescriptionCase, LengthUnit};
+use crate::fix::{Applicability, Suggestion};
+use crate::length;
+use crate::lint::{Rule, RuleContext};
+use crate::parser::{ParseError, ParseErrorKind};
+use crate::wording;
+
+/// The style rules enabled by a configuration
+pub fn rules_from_config(config: &Config) -> Vec<Box<dyn Rule>> {
+    let description = &config.description;
+    let unit = config.length_unit;
+    let mut rules: Vec<Box<dyn Rule>> = Vec::new();
+    if description.case != DescriptionCase::Any {
+        rules.push(Box::new(DescriptionCaseRule(description.case)));
+    }
+    if description.no_trailing_period {
+        rules.push(Box::new(NoTrailingPeriod));
+    }
+    if let Some(min) = description.min_length {
+        rules.push(Box::new(DescriptionMinLength { min, unit }));
+    }
+    if let Some(max) = description.max_length {
+        rules.push(Box::new(DescriptionMaxLength { max, unit }));
+    }
+    if let Some(max) = config.header_max_length {
+        rules.push(Box::new(HeaderMaxLength { max, unit }));
+    }
+    if description.imperative {
+        rules.push(Box::new(ImperativeMood));
+    }
+    if description.no_repeated_type {
+        rules.push(Box::new(NoRepeatedType));
+    }
+    if description.no_vague {
+        rules.push(Box::new(NoVagueDescription));
+    }
+    rules
+}
+
+/// The first letter of the description must have the given case
+pub struct DescriptionCaseRule(pub DescriptionCase);
+
+impl Rule for DescriptionCaseRule {
+    fn name(&self) -> &str {
+        "description-case"
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<ParseError> {
+        let description = &context.message.header.description;
+        let Some(first) = description.chars().next() else {
+            return Vec::new();
+        };
+        let first_word = description.split_whitespace().next().unwrap_or_default();
+        let is_acronym = first_word.chars().filter(|c| c.is_alphabetic()).count() > 1
+            && !first_word.chars().any(char::is_lowercase);
+
+        let wrong_case = match self.0 {
+            DescriptionCase::LowerFirst => first.is_uppercase() && !is_acronym,
+            DescriptionCase::Sentence => first.is_lowercase(),
+            DescriptionCase::Any => false,
+        };
+        if !wrong_case {
+            return Vec::new();
+        }
+        let start = context.description_span().start;
+        vec![ParseError::new(
+            ParseErrorKind::DescriptionCase { expected: self.0 },
+            start..start + 1,
+        )]
+    }
+}
+
+/// The description must not end with a period; an ellipsis counts as one
+pub struct NoTrailingPeriod;
+
+impl Rule for NoTrailingPeriod {
+    fn name(&self) -> &str {
+        "description-trailing-period"
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<ParseError> {
+        let description = &context.message.header.description;
+        let periods = description.chars().rev().take_while(|&c| c == '.').count();
+        if periods == 0 {
+            return Vec::new();
+        }
+        let end = context.description_span().end;
+        vec![ParseError::new(
+            ParseErrorKind::DescriptionTrailingPeriod,
+            end - periods..end,
+        )]
+    }
+}
+
+/// Suggestion that drops the words of `text` (starting at character `start`) past `max`,
+/// unless that would keep no more than `min_keep` characters
+fn truncation(
+    text: &str,
+    start: usize,
+    max: usize,
+    unit: LengthUnit,
+    min_keep: usize,
+) -> Option<Suggestion> {
+    let keep = length::truncate_at_word(text, max, unit)?;
+    (keep > min_keep).then(|| {
+        Suggestion::new(
+            start + keep..start + text.chars().count(),
+            "",
+            Applicability::MaybeIncorrect,
+        )
+    })
+}
+
+/// The description must be at least `min` long
+pub struct DescriptionMinLength {
+    pub min: usize,
+    pub unit: LengthUnit,
+}
+
+impl Rule for DescriptionMinLength {
+    fn name(&self) -> &str {
+        "description-too-short"
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<ParseError> {
+        let length = length::measure(&context.message.header.description, self.unit);
+        if length >= self.min {
+            return Vec::new();
+        }
+        vec![ParseError::new(
+            ParseErrorKind::DescriptionTooShort {
+                min: self.min,
+                length,
+                unit: self.unit,
+            },
+            context.description_span(),
+        )]
+    }
+}
+
+/// The description must be at most `max` long; the span covers the excess
+pub struct DescriptionMaxLength {
+    pub max: usize,
+    pub unit: LengthUnit,
+}
+
+impl Rule for DescriptionMaxLength {
+    fn name(&self) -> &str {
+        "description-too-long"
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<ParseError> {
+        let description = &context.message.header.description;
+        let length = length::measure(description, self.unit);
+        if length <= self.max {
+            return Vec::new();
+        }
+        let span = context.description_span();
+        let fits = length::fitting_chars(description, self.max, self.unit);
+        let mut error = ParseError::new(
+            ParseErrorKind::DescriptionTooLong {
+                max: self.max,
+                length,
+                unit: self.unit,
+            },
+            span.start + fits..span.end,
+        );
+        error.suggestion = truncation(description, span.start, self.max, self.unit, 0);
+        vec![error]
+    }
+}
+
+/// The header must be at most `max` long; the span covers the excess
+pub struct HeaderMaxLength {
+    pub max: usize,
+    pub unit: LengthUnit,
+}
+
+impl Rule for HeaderMaxLength {
+    fn name(&self) -> &str {
+        "header-too-long"
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<ParseError> {
+        let start = context.type_span().start;
+        let description = context.description_span();
+        let header: String = context
+            .header()
+            .chars()
+            .skip(start)
+            .take(description.end - start)
+            .collect();
+        let length = length::measure(&header, self.unit);
+        if length <= self.max {
+            return Vec::new();
+        }
+        let fits = length::fitting_chars(&header, self.max, self.unit);
+        let mut error = ParseError::new(
+            ParseErrorKind::HeaderTooLong {
+                max: self.max,
+                length,
+                unit: self.unit,
+            },
+            start + fits..description.end,
+        );
+        // Only cut words off the description, never the type or scope
+        let prefix = description.start - start;
+        error.suggestion = truncation(&header, start, self.max, self.unit, prefix);
+        vec![error]
+    }
+}
+
+/// Span and text of the first word of the description, up to the first non-letter
+fn first_word(context: &RuleContext) -> Option<(std::ops::Range<usize>, String)> {
+    let word: String = context
+        .message
+        .header
+        .description
+        .chars()
+        .take_while(|c| c.is_alphabetic())
+        .collect();
+    if word.is_empty() {
+        return None;
+    }
+    let start = context.description_span().start;
+    Some((start..start + word.chars().count(), word))
+}
+
+/// The description must start with an imperative verb: `add`, not `added` or `adding`
+pub struct ImperativeMood;
+
+impl Rule for ImperativeMood {
+    fn name(&self) -> &str {
+        "description-not-imperative"
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<ParseError> {
+        let Some((span, word)) = first_word(context) else {
+            return Vec::new();
+        };
+        let Some(imperative) = wording::imperative_for(&context.message.header.description) else {
+            return Vec::new();
+        };
+        vec![ParseError::new(
+            ParseErrorKind::DescriptionNotImperative {
+                found: word,
+                imperative: imperative.to_string(),
+            },
+            span,
+        )]
+    }
+}
+
+/// The description must not start with its own type, like `fix: fix crash`
+pub struct NoRepeatedType;
+
+impl Rule for NoRepeatedType {
+    fn name(&self) -> &str {
+        "description-repeats-type"
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<ParseError> {
+        let Some((span, word)) = first_word(context) else {
+            return Vec::new();
+        };
+        let commit_type = &context.message.header.commit_type;
+        let word = word.to_lowercase();
+        let repeats = word == commit_type.to_lowercase()
+            || wording::imperative_of(&word) == Some(commit_type.as_str());
+        if !repeats {
+            return Vec::new();
+        }
+        vec![ParseError::new(
+            ParseErrorKind::DescriptionRepeatsType {
+                commit_type: commit_type.clone(),
+            },
+            span,
+        )]
+    }
+}
+
+/// The description must say more than vague words like `update` or `minor fixes`
+pub struct NoVagueDescription;
+
+impl Rule for NoVagueDescription {
+    fn name(&self) -> &str {
+        "vague-description"
+    }
+
+    fn check(&self, context: &RuleContext) -> Vec<ParseError> {
+        if !wording::is_vague(&context.message.header.description) {
+            return Vec::new();
+        }
+        vec![ParseError::new(
+            ParseErrorKind::VagueDescription,
+            context.description_span(),
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DescriptionConfig;
+    use crate::lint::Linter;
+
+    fn linter(description: DescriptionConfig, header_max_length: Option<usize>) -> Linter {
+        Linter::from_config(&Config {
+            description,
+            header_max_length,
+            ..Config::default()
+        })
+    }
+
+    fn kinds(linter: &Linter, input: &str) -> Vec<(String, std::ops::Range<usize>)> {
+        linter
+            .lint(input)
+            .diagnostics()
+            .into_iter()
+            .map(|error| (error.kind.name().to_string(), error.span))
+            .collect()
+    }
+
+    #[test]
+    fn test_rules_are_off_by_default() {
+        let linter = Linter::from_config(&Config::default());
+        assert!(linter.lint("feat: Add a very long description.").is_ok());
+    }
+
+    #[test]
+    fn test_lower_first_case() {
+        let linter = linter(
+            DescriptionConfig {
+                case: DescriptionCase::LowerFirst,
+                ..DescriptionConfig::default()
+            },
+            None,
+        );
+        assert_eq!(
+            kinds(&linter, "feat(api): Add login"),
+            vec![("description-case".to_string(), 11..12)]
+        );
+        assert!(linter.lint("feat: add login").is_ok());
+        assert!(linter.lint("feat: API keys for login").is_ok());
+        assert!(linter.lint("feat: 2fa login").is_ok());
+        assert_eq!(linter.fix_message("feat: Add login"), "feat: add login");
+    }
+
+    #[test]
+    fn test_sentence_case() {
+        let linter = linter(
+            DescriptionConfig {
+                case: DescriptionCase::Sentence,
+                ..DescriptionConfig::default()
+            },
+            None,
+        );
+        assert!(linter.lint("feat: add login").is_err());
+        assert!(linter.lint("feat: Add login").is_ok());
+        assert_eq!(linter.fix_message("feat: add login"), "feat: Add login");
+    }
+
+    #[test]
+    fn test_no_trailing_period() {
+        let linter = linter(
+            DescriptionConfig {
+                no_trailing_period: true,
+                ..DescriptionConfig::default()
+            },
+            None,
+        );
+        assert_eq!(
+            kinds(&linter, "fixup! feat: add login..."),
+            vec![("description-trailing-period".to_string(), 22..25)]
+        );
+        assert!(linter.lint("feat: add login!").is_ok());
+        assert_eq!(linter.fix_message("feat: add login.  "), "feat: add login");
+    }
+
+    #[test]
+    fn test_description_length() {
+        let linter = linter(
+            DescriptionConfig {
+                min_length: Some(5),
+                max_length: Some(10),
+                ..DescriptionConfig::default()
+            },
+            None,
+        );
+        assert_eq!(
+            kinds(&linter, "feat: fix"),
+            vec![("description-too-short".to_string(), 6..9)]
+        );
+        assert_eq!(
+            kinds(&linter, "feat: add the login"),
+            vec![("description-too-long".to_string(), 16..19)]
+        );
+        assert!(linter.lint("feat: add login").is_ok());
+        assert_eq!(linter.fix_message("feat: add the login"), "feat: add the");
+        // The placeholder for short descriptions is only a suggestion
+        assert_eq!(linter.fix_message("feat: fix"), "feat: fix");
+    }
+
+    #[test]
+    fn test_header_max_length() {
+        let linter = linter(DescriptionConfig::default(), Some(15));
+        assert_eq!(
+            kinds(&linter, "feat(api): add the login"),
+            vec![("header-too-long".to_string(), 15..24)]
+        );
+        assert!(linter.lint("squash! feat: add login").is_ok());
+        assert_eq!(
+            linter.fix_message("feat(api): add the login"),
+            "feat(api): add"
+        );
+    }
+
+    #[test]
+    fn test_length_counts_display_width() {
+        let linter = linter(
+            DescriptionConfig {
+                max_length: Some(10),
+                ..DescriptionConfig::default()
+            },
+            None,
+        );
+        assert!(linter.lint("feat: ééééééééé\u{301}").is_ok());
+        assert!(linter.lint("feat: 로그인 추가").is_err());
+        // The excess starts at the first character that does not fit
+        assert_eq!(
+            kinds(&linter, "feat: 로그인 추가 🎉"),
+            vec![("description-too-long".to_string(), 11..14)]
+        );
+        assert_eq!(linter.fix_message("feat: 로그인 추가 🎉"), "feat: 로그인");
+    }
+
+    #[test]
+    fn test_length_in_graphemes() {
+        let linter = Linter::from_config(&Config {
+            header_max_length: Some(12),
+            length_unit: LengthUnit::Graphemes,
+            ..Config::default()
+        });
+        assert!(linter.lint("feat: 로그인 추가").is_ok());
+        assert!(linter.lint("feat: 👨\u{200d}👩\u{200d}👧 add").is_ok());
+        let error = &linter.lint("feat: 로그인 추가 기능").unwrap_err()[0];
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::HeaderTooLong {
+                max: 12,
+                length: 15,
+                unit: LengthUnit::Graphemes
+            }
+        );
+        assert_eq!(error.span, 12..15);
+    }
+
+    #[test]
+    fn test_imperative_mood() {
+        let linter = linter(
+            DescriptionConfig {
+                imperative: true,
+                ..DescriptionConfig::default()
+            },
+            None,
+        );
+        assert_eq!(
+            kinds(&linter, "feat(api): added login"),
+            vec![("description-not-imperative".to_string(), 11..16)]
+        );
+        assert!(linter.lint("feat: add login").is_ok());
+        assert!(linter.lint("docs: readme updates").is_ok());
+        assert_eq!(linter.fix_message("feat: adding login"), "feat: add login");
+        assert_eq!(linter.fix_message("feat: Adds login"), "feat: Add login");
+        assert_eq!(linter.fix_message("fix: wrote tests"), "fix: write tests");
+    }
+
+    #[test]
+    fn test_imperative_mood_ignores_participles_as_adjectives() {
+        let linter = linter(
+            DescriptionConfig {
+                imperative: true,
+                ..DescriptionConfig::default()
+            },
+            None,
+        );
+        for message in [
+            "fix: broken links",
+            "feat: built-in themes",
+            "style: left-align labels",
+            "feat: logging for requests",
+            "fix: hidden fields",
+        ] {
+            assert!(linter.lint(message).is_ok(), "{message}");
+            assert_eq!(linter.fix_message(message), message);
+        }
+    }
+
+    #[test]
+    fn test_no_repeated_type() {
+        let linter = linter(
+            DescriptionConfig {
+                no_repeated_type: true,
+                ..DescriptionConfig::default()
+            },
+            None,
+        );
+        assert_eq!(
+            kinds(&linter, "fix: fix crash on start"),
+            vec![("description-repeats-type".to_string(), 5..8)]
+        );
+        assert!(linter.lint("fix: fixes crash").is_err());
+        assert!(linter.lint("fix: prefix routes").is_ok());
+        assert!(linter.lint("feat: fix crash").is_ok());
+        assert_eq!(linter.fix_message("fix: Fix crash"), "fix: crash");
+        assert_eq!(linter.fix_message("fix: fix"), "fix: fix");
+    }
+
+    #[test]
+    fn test_no_vague_description() {
+        let linter = linter(
+            DescriptionConfig {
+                no_vague: true,
+                ..DescriptionConfig::default()
+            },
+            None,
+        );
+        assert_eq!(
+            kinds(&linter, "chore: minor changes"),
+            vec![("vague-description".to_string(), 7..20)]
+        );
+        assert!(linter.lint("fix: WIP").is_err());
+        assert!(linter.lint("chore: update dependencies").is_ok());
+    }
+}
@@ -0,0 +1,320 @@
+// Git trailer parsing following `git interpret-trailers` rules
+
+use crate::parser::{ParseError, ParseErrorKind};
+
+/// A git trailer such as `Refs: #123`, `Fixes #42` or `Co-authored-by: Name <email>`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trailer {
+    pub token: String,
+    pub separator: char,
+    pub value: String,
+    pub span: std::ops::Range<usize>,
+}
+
+/// The name `CommitMessage` trailers first shipped under.
+#[deprecated(note = "renamed to `Trailer`, the git name for message footers")]
+pub type Footer = Trailer;
+
+impl Trailer {
+    pub fn is_breaking_change(&self) -> bool {
+        self.token == "BREAKING CHANGE" || self.token == "BREAKING-CHANGE"
+    }
+}
+
+enum TrailerLine<'a> {
+    Trailer {
+        token: &'a str,
+        separator: char,
+        value: &'a str,
+    },
+    Continuation(&'a str),
+    Malformed(ParseErrorKind, std::ops::Range<usize>),
+}
+
+/// Classify a single line of a trailer block.
+/// Tokens are alphanumerics and '-' (plus the spec's `BREAKING CHANGE`), optionally
+/// followed by whitespace, then ':' or '#'. Indented lines continue the previous value.
+fn classify(line: &str) -> TrailerLine<'_> {
+    if line.starts_with([' ', '\t']) {
+        return TrailerLine::Continuation(line.trim());
+    }
+
+    let token_len = if line.starts_with("BREAKING CHANGE") {
+        "BREAKING CHANGE".len()
+    } else {
+        line.find(|c: char| !(c.is_alphanumeric() || c == '-'))
+            .unwrap_or(line.len())
+    };
+    let (token, rest) = line.split_at(token_len);
+    let after_space = rest.trim_start_matches([' ', '\t']);
+
+    if !token.is_empty()
+        && let Some(separator) = after_space
+            .chars()
+            .next()
+            .filter(|c| matches!(c, ':' | '#'))
+    {
+        return TrailerLine::Trailer {
+            token,
+            separator,
+            value: after_space[separator.len_utf8()..].trim(),
+        };
+    }
+
+    let line_len = line.chars().count();
+    match line.split_once(':') {
+        Some((token, _)) if token.trim().contains(char::is_whitespace) => {
+            let token = token.trim_end();
+            TrailerLine::Malformed(
+                ParseErrorKind::TrailerTokenWithSpaces {
+                    token: token.to_string(),
+                },
+                0..token.chars().count(),
+            )
+        }
+        _ => TrailerLine::Malformed(ParseErrorKind::MissingTrailerSeparator, 0..line_len),
+    }
+}
+
+/// Well-known trailer tokens. A last paragraph that uses one of them is meant as a
+/// trailer block, even when some of its lines are malformed.
+const KNOWN_TOKENS: &[&str] = &[
+    "Refs",
+    "Signed-off-by",
+    "Co-authored-by",
+    "Reviewed-by",
+    "Acked-by",
+    "Tested-by",
+    "Reported-by",
+    "Suggested-by",
+    "Helped-by",
+];
+
+/// Line added by `git cherry-pick -x`, which git accepts in a trailer block
+const CHERRY_PICKED_PREFIX: &str = "(cherry picked from commit ";
+
+/// Whether `line` starts with a known token, even a misspelled one like `Reviewed by`
+/// or `Signed-off-by Alice`. Case is ignored, and a space may stand in for a '-'.
+fn starts_with_known_token(line: &str) -> bool {
+    KNOWN_TOKENS.iter().any(|token| {
+        let mut rest = line.chars();
+        let matches = token.chars().all(|expected| {
+            rest.next().is_some_and(|c| {
+                c.eq_ignore_ascii_case(&expected) || (expected == '-' && c == ' ')
+            })
+        });
+        matches && rest.next().is_none_or(|c| matches!(c, ':' | '#' | ' ' | '\t'))
+    })
+}
+
+/// Index of the first line of the trailer block in `lines`, or `lines.len()` if there is none.
+///
+/// Like git, only the last paragraph is considered. It is a trailer block when most of its
+/// non-continuation lines are trailers, or when it uses a well-known token such as `Refs`
+/// or `Reviewed-by`; its other lines are then reported as malformed trailers.
+pub(crate) fn find_trailer_block(lines: &[(usize, &str)]) -> usize {
+    let start = lines
+        .iter()
+        .rposition(|(_, line)| line.trim().is_empty())
+        .map_or(0, |idx| idx + 1);
+
+    let mut trailers = 0;
+    let mut others = 0;
+    let mut known_token = false;
+    for (_, line) in &lines[start..] {
+        if line.starts_with(CHERRY_PICKED_PREFIX) {
+            known_token = true;
+            continue;
+        }
+        match classify(line) {
+            TrailerLine::Trailer { .. } => trailers += 1,
+            TrailerLine::Continuation(_) => continue,
+            TrailerLine::Malformed(..) => others += 1,
+        }
+        known_token |= starts_with_known_token(line);
+    }
+
+    if trailers > others || known_token {
+        start
+    } else {
+        lines.len()
+    }
+}
+
+/// Parse a trailer block into trailers, collecting errors for malformed lines.
+/// `lines` pairs each line with its character offset in the whole message.
+pub(crate) fn parse_trailers(lines: &[(usize, &str)]) -> (Vec<Trailer>, Vec<ParseError>) {
+    let mut trailers: Vec<Trailer> = Vec::new();
+    let mut errors = Vec::new();
+
+    for &(start, line) in lines {
+        if line.starts_with(CHERRY_PICKED_PREFIX) {
+            continue;
+        }
+        let end = start + line.chars().count();
+        match classify(line) {
+            TrailerLine::Trailer {
+                token,
+                separator,
+                value,
+            } => trailers.push(Trailer {
+                token: token.to_string(),
+                separator,
+                value: value.to_string(),
+                span: start..end,
+            }),
+            TrailerLine::Continuation(text) => match trailers.last_mut() {
+                Some(trailer) => {
+                    trailer.value.push('\n');
+                    trailer.value.push_str(text);
+                    trailer.span.end = end;
+                }
+                None => errors.push(ParseError::new(
+                    ParseErrorKind::MissingTrailerSeparator,
+                    start..end,
+                )),
+            },
+            TrailerLine::Malformed(kind, span) => {
+                errors.push(ParseError::new(kind, start + span.start..start + span.end));
+            }
+        }
+    }
+
+    (trailers, errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(input: &str) -> Vec<(usize, &str)> {
+        let mut offset = 0;
+        input
+            .split('\n')
+            .map(|line| {
+                let entry = (offset, line);
+                offset += line.chars().count() + 1;
+                entry
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_colon_and_hash_separators() {
+        let (trailers, errors) = parse_trailers(&lines("Refs: #123\nFixes #42"));
+        assert!(errors.is_empty());
+        assert_eq!(trailers[0].token, "Refs");
+        assert_eq!(trailers[0].separator, ':');
+        assert_eq!(trailers[0].value, "#123");
+        assert_eq!(trailers[1].token, "Fixes");
+        assert_eq!(trailers[1].separator, '#');
+        assert_eq!(trailers[1].value, "42");
+    }
+
+    #[test]
+    fn test_value_without_space_after_colon() {
+        let (trailers, errors) = parse_trailers(&lines("Reviewed-by:Alice"));
+        assert!(errors.is_empty());
+        assert_eq!(trailers[0].value, "Alice");
+    }
+
+    #[test]
+    fn test_indented_continuation_lines() {
+        let (trailers, errors) = parse_trailers(&lines(
+            "Co-authored-by: Alice\n  <alice@example.com>\nRefs: #1",
+        ));
+        assert!(errors.is_empty());
+        assert_eq!(trailers.len(), 2);
+        assert_eq!(trailers[0].value, "Alice\n<alice@example.com>");
+        assert_eq!(trailers[0].span, 0..43);
+    }
+
+    #[test]
+    fn test_breaking_change_token_may_contain_space() {
+        let (trailers, errors) = parse_trailers(&lines("BREAKING CHANGE: drop v1"));
+        assert!(errors.is_empty());
+        assert!(trailers[0].is_breaking_change());
+    }
+
+    #[test]
+    fn test_token_with_spaces() {
+        let (_, errors) = parse_trailers(&lines("Refs: #1\nReviewed by: Alice"));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].kind,
+            ParseErrorKind::TrailerTokenWithSpaces {
+                token: "Reviewed by".to_string()
+            }
+        );
+        assert_eq!(errors[0].span, 9..20);
+    }
+
+    #[test]
+    fn test_missing_separator() {
+        let (_, errors) = parse_trailers(&lines("Refs: #1\nSigned-off-by Alice"));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, ParseErrorKind::MissingTrailerSeparator);
+        assert_eq!(errors[0].span, 9..28);
+    }
+
+    #[test]
+    fn test_trailer_block_must_be_last_paragraph() {
+        let input = lines("Refs: #1\n\nJust prose.");
+        assert_eq!(find_trailer_block(&input), input.len());
+
+        let input = lines("Just prose.\n\nRefs: #1");
+        assert_eq!(find_trailer_block(&input), 2);
+    }
+
+    #[test]
+    fn test_prose_paragraph_is_not_a_trailer_block() {
+        let input = lines("Note: this is a sentence\nthat continues here\nand here.");
+        assert_eq!(find_trailer_block(&input), input.len());
+    }
+
+    #[test]
+    fn test_prose_with_colons_is_not_a_trailer_block() {
+        let input = lines("Works around: the old parser\nSee https://example.com for details");
+        assert_eq!(find_trailer_block(&input), input.len());
+
+        let input = lines("See https://example.com for details");
+        assert_eq!(find_trailer_block(&input), input.len());
+    }
+
+    #[test]
+    fn test_mostly_trailers_form_a_block() {
+        let input = lines("Refs: #1\nCo-authored-by: Alice\n  <alice@example.com>");
+        assert_eq!(find_trailer_block(&input), 0);
+
+        let input = lines("Fixes: #1\nCloses: #2\nWorks around: the old parser");
+        assert_eq!(find_trailer_block(&input), 0);
+        let (_, errors) = parse_trailers(&input);
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn test_known_token_forms_a_block() {
+        let input = lines("Refs: #1\nReviewed by: Alice");
+        assert_eq!(find_trailer_block(&input), 0);
+
+        let input = lines("Signed-off-by Alice");
+        assert_eq!(find_trailer_block(&input), 0);
+        let (_, errors) = parse_trailers(&input);
+        assert_eq!(errors[0].kind, ParseErrorKind::MissingTrailerSeparator);
+
+        let input = lines("co-authored by: Bob\nsome prose\nmore prose");
+        assert_eq!(find_trailer_block(&input), 0);
+
+        let input = lines("Refsomething is odd here");
+        assert_eq!(find_trailer_block(&input), input.len());
+    }
+
+    #[test]
+    fn test_cherry_pick_note_is_accepted_in_a_block() {
+        let input = lines("(cherry picked from commit abc123)\nReviewed by: Alice");
+        assert_eq!(find_trailer_block(&input), 0);
+        let (trailers, errors) = parse_trailers(&input);
+        assert!(trailers.is_empty());
+        assert_eq!(errors.len(), 1);
+    }
+}
@@ -0,0 +1,374 @@
+// English wording heuristics for descriptions: imperative mood and vague phrasing
+
+/// Verbs commonly used in commit descriptions, in their imperative (base) form
+const VERBS: &[&str] = &[
+    "accept",
+    "add",
+    "adjust",
+    "align",
+    "allow",
+    "apply",
+    "avoid",
+    "begin",
+    "break",
+    "bring",
+    "build",
+    "bump",
+    "cache",
+    "call",
+    "catch",
+    "change",
+    "check",
+    "clarify",
+    "clean",
+    "close",
+    "collect",
+    "combine",
+    "commit",
+    "configure",
+    "convert",
+    "copy",
+    "correct",
+    "cover",
+    "create",
+    "cut",
+    "decouple",
+    "define",
+    "delete",
+    "deprecate",
+    "detect",
+    "disable",
+    "display",
+    "document",
+    "downgrade",
+    "drop",
+    "emit",
+    "enable",
+    "ensure",
+    "expose",
+    "extend",
+    "extract",
+    "find",
+    "fix",
+    "format",
+    "generate",
+    "get",
+    "give",
+    "handle",
+    "hide",
+    "hold",
+    "ignore",
+    "implement",
+    "improve",
+    "include",
+    "increase",
+    "initialize",
+    "inline",
+    "install",
+    "integrate",
+    "introduce",
+    "keep",
+    "lead",
+    "leave",
+    "limit",
+    "load",
+    "log",
+    "lose",
+    "make",
+    "map",
+    "merge",
+    "migrate",
+    "move",
+    "normalize",
+    "omit",
+    "optimize",
+    "parse",
+    "pass",
+    "pin",
+    "polish",
+    "prefer",
+    "prepare",
+    "prevent",
+    "print",
+    "put",
+    "read",
+    "reduce",
+    "refactor",
+    "release",
+    "reload",
+    "remove",
+    "rename",
+    "render",
+    "reorder",
+    "replace",
+    "report",
+    "require",
+    "reset",
+    "resolve",
+    "restore",
+    "restructure",
+    "return",
+    "revert",
+    "rework",
+    "rewrite",
+    "run",
+    "save",
+    "send",
+    "set",
+    "ship",
+    "show",
+    "simplify",
+    "skip",
+    "sort",
+    "speed",
+    "split",
+    "start",
+    "stop",
+    "store",
+    "strip",
+    "support",
+    "swap",
+    "switch",
+    "take",
+    "test",
+    "throw",
+    "track",
+    "trim",
+    "tweak",
+    "understand",
+    "update",
+    "upgrade",
+    "use",
+    "validate",
+    "wrap",
+    "write",
+];
+
+/// Verbs that double their final consonant before `-ed` and `-ing` (`drop` -> `dropped`)
+const DOUBLED: &[&str] = &[
+    "begin", "commit", "cut", "drop", "emit", "get", "log", "map", "omit", "pin", "put", "run",
+    "set", "ship", "skip", "split", "stop", "strip", "swap", "trim", "wrap",
+];
+
+/// Irregular past tenses and participles. Forms that are mostly used as adjectives
+/// (`broken`, `built`, `hidden`, `left`, `lost`, `found`, `led`) are left out.
+const IRREGULAR: &[(&str, &str)] = &[
+    ("began", "begin"),
+    ("begun", "begin"),
+    ("broke", "break"),
+    ("brought", "bring"),
+    ("caught", "catch"),
+    ("gave", "give"),
+    ("given", "give"),
+    ("got", "get"),
+    ("hid", "hide"),
+    ("held", "hold"),
+    ("kept", "keep"),
+    ("made", "make"),
+    ("ran", "run"),
+    ("rewrote", "rewrite"),
+    ("rewritten", "rewrite"),
+    ("sent", "send"),
+    ("sped", "speed"),
+    ("taken", "take"),
+    ("took", "take"),
+    ("threw", "throw"),
+    ("thrown", "throw"),
+    ("understood", "understand"),
+    ("wrote", "write"),
+    ("written", "write"),
+];
+
+/// `-ing` forms that usually name a feature rather than an action (`logging for requests`)
+const NOUN_GERUNDS: &[&str] = &["caching", "formatting", "logging", "testing", "tracking"];
+
+/// Words that cannot start the object of a verb, so an inflected verb followed by one
+/// is more likely an adjective or a noun (`caching for builds`, `fixed in v2`)
+const NON_OBJECTS: &[&str] = &[
+    "after", "and", "are", "as", "at", "before", "but", "by", "during", "for", "from", "in",
+    "into", "is", "of", "on", "or", "over", "to", "under", "via", "was", "were", "when", "while",
+    "with", "without",
+];
+
+/// Words that say nothing about a change on their own
+const VAGUE_WORDS: &[&str] = &[
+    "bug",
+    "bugs",
+    "change",
+    "changes",
+    "changed",
+    "cleanup",
+    "code",
+    "file",
+    "files",
+    "fix",
+    "fixed",
+    "fixes",
+    "improvement",
+    "improvements",
+    "minor",
+    "misc",
+    "more",
+    "some",
+    "small",
+    "stuff",
+    "temp",
+    "things",
+    "tmp",
+    "tweak",
+    "tweaks",
+    "update",
+    "updated",
+    "updates",
+    "various",
+    "wip",
+];
+
+fn ends_with_consonant_y(word: &str) -> bool {
+    let mut chars = word.chars().rev();
+    chars.next() == Some('y') && chars.next().is_some_and(|c| !"aeiou".contains(c))
+}
+
+fn third_person(verb: &str) -> String {
+    if ends_with_consonant_y(verb) {
+        format!("{}ies", &verb[..verb.len() - 1])
+    } else if ["s", "x", "z", "ch", "sh", "o"]
+        .iter()
+        .any(|suffix| verb.ends_with(suffix))
+    {
+        format!("{verb}es")
+    } else {
+        format!("{verb}s")
+    }
+}
+
+fn past(verb: &str) -> String {
+    if ends_with_consonant_y(verb) {
+        format!("{}ied", &verb[..verb.len() - 1])
+    } else if verb.ends_with('e') {
+        format!("{verb}d")
+    } else if DOUBLED.contains(&verb) {
+        format!("{verb}{}ed", &verb[verb.len() - 1..])
+    } else {
+        format!("{verb}ed")
+    }
+}
+
+fn gerund(verb: &str) -> String {
+    if verb.ends_with('e') && !verb.ends_with("ee") {
+        format!("{}ing", &verb[..verb.len() - 1])
+    } else if DOUBLED.contains(&verb) {
+        format!("{verb}{}ing", &verb[verb.len() - 1..])
+    } else {
+        format!("{verb}ing")
+    }
+}
+
+/// The imperative form of a known verb inflected as `-s`, `-ed` or `-ing` (`added` -> `add`).
+/// `None` for imperatives and for words that are not known verbs.
+pub fn imperative_of(word: &str) -> Option<&'static str> {
+    let word = word.to_lowercase();
+    if let Some((_, verb)) = IRREGULAR.iter().find(|(form, _)| *form == word) {
+        return Some(verb);
+    }
+    if NOUN_GERUNDS.contains(&word.as_str()) {
+        return None;
+    }
+    VERBS.iter().copied().find(|verb| {
+        *verb != word && (third_person(verb) == word || past(verb) == word || gerund(verb) == word)
+    })
+}
+
+/// The imperative to use for the first word of a description that starts with an
+/// inflected verb and its object (`added login` -> `add`). Compounds like `built-in` and
+/// words not followed by an object (`fixed in v2`) are not flagged.
+pub fn imperative_for(description: &str) -> Option<&'static str> {
+    let end = description
+        .find(|c: char| !c.is_alphabetic())
+        .unwrap_or(description.len());
+    let (word, rest) = description.split_at(end);
+    let rest = rest.strip_prefix(' ')?.trim_start();
+    let next: String = rest.chars().take_while(|c| c.is_alphabetic()).collect();
+    let object = match rest.chars().next() {
+        Some(c) if c.is_alphabetic() => !NON_OBJECTS.contains(&next.to_lowercase().as_str()),
+        Some(c) => c.is_numeric() || "`'\"(".contains(c),
+        None => false,
+    };
+    if !object {
+        return None;
+    }
+    imperative_of(word)
+}
+
+/// Whether a description consists only of vague words, like `update` or `minor fixes`
+pub fn is_vague(description: &str) -> bool {
+    let mut words = description
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .peekable();
+    words.peek().is_some() && words.all(|word| VAGUE_WORDS.contains(&word.to_lowercase().as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_imperative_of_regular_verbs() {
+        assert_eq!(imperative_of("added"), Some("add"));
+        assert_eq!(imperative_of("adding"), Some("add"));
+        assert_eq!(imperative_of("adds"), Some("add"));
+        assert_eq!(imperative_of("Fixes"), Some("fix"));
+        assert_eq!(imperative_of("updated"), Some("update"));
+        assert_eq!(imperative_of("updating"), Some("update"));
+        assert_eq!(imperative_of("clarified"), Some("clarify"));
+        assert_eq!(imperative_of("dropped"), Some("drop"));
+        assert_eq!(imperative_of("running"), Some("run"));
+    }
+
+    #[test]
+    fn test_imperative_of_irregular_verbs() {
+        assert_eq!(imperative_of("wrote"), Some("write"));
+        assert_eq!(imperative_of("took"), Some("take"));
+        assert_eq!(imperative_of("made"), Some("make"));
+    }
+
+    #[test]
+    fn test_imperative_for_verbs_with_objects() {
+        assert_eq!(imperative_for("added login"), Some("add"));
+        assert_eq!(imperative_for("Fixes the crash"), Some("fix"));
+        assert_eq!(imperative_for("wrote `README` section"), Some("write"));
+        assert_eq!(imperative_for("dropped 32-bit builds"), Some("drop"));
+    }
+
+    #[test]
+    fn test_imperative_for_ignores_adjectives_and_nouns() {
+        assert_eq!(imperative_for("broken links"), None);
+        assert_eq!(imperative_for("built-in themes"), None);
+        assert_eq!(imperative_for("left-align labels"), None);
+        assert_eq!(imperative_for("logging for requests"), None);
+        assert_eq!(imperative_for("hidden fields"), None);
+        assert_eq!(imperative_for("lost updates on reconnect"), None);
+        assert_eq!(imperative_for("caching layer"), None);
+        assert_eq!(imperative_for("fixed in v2"), None);
+        assert_eq!(imperative_for("added"), None);
+        assert_eq!(imperative_for("add login"), None);
+    }
+
+    #[test]
+    fn test_imperatives_and_unknown_words() {
+        assert_eq!(imperative_of("add"), None);
+        assert_eq!(imperative_of("set"), None);
+        assert_eq!(imperative_of("readme"), None);
+        assert_eq!(imperative_of("api"), None);
+    }
+
+    #[test]
+    fn test_is_vague() {
+        assert!(is_vague("update"));
+        assert!(is_vague("Minor fixes."));
+        assert!(is_vague("WIP"));
+        assert!(!is_vague("update login copy"));
+        assert!(!is_vague(""));
+    }
+}
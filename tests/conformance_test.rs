@@ -0,0 +1,243 @@
+// Conventional Commits 1.0.0 conformance, one test per numbered clause of
+// https://www.conventionalcommits.org/en/v1.0.0/#specification, in spec-strict mode
+
+use conventional_prs::{
+    CommitMessage, Config, DescriptionCase, DescriptionConfig, Linter, ParserMode,
+};
+
+fn linter() -> Linter {
+    Linter::from_config(&Config {
+        types: ["feat", "fix", "docs", "refactor", "chore"]
+            .into_iter()
+            .map(Into::into)
+            .collect(),
+        mode: ParserMode::SpecStrict,
+        ..Config::default()
+    })
+}
+
+fn parse(message: &str) -> CommitMessage {
+    match linter().lint(message).into_result() {
+        Ok(message) => message,
+        Err(errors) => panic!("{message:?} should be valid: {errors:?}"),
+    }
+}
+
+fn codes(message: &str) -> Vec<String> {
+    codes_with(&linter(), message)
+}
+
+fn codes_with(linter: &Linter, message: &str) -> Vec<String> {
+    linter
+        .lint(message)
+        .errors()
+        .unwrap_or_default()
+        .iter()
+        .map(|error| error.kind.code().to_string())
+        .collect()
+}
+
+#[test]
+fn clause_01_type_prefix_with_colon_and_space() {
+    let message = parse("feat(api)!: add login");
+    assert_eq!(message.header.commit_type, "feat");
+    assert_eq!(message.header.scope, Some(vec!["api".to_string()]));
+    assert!(message.header.breaking);
+
+    assert_eq!(codes("feat add login"), ["CP0013", "CP0014"]);
+    assert_eq!(codes("feat:add login"), ["CP0014"]);
+    assert_eq!(codes("feat : add login"), ["CP0011"]);
+    assert_eq!(codes(": add login"), ["CP0007"]);
+}
+
+#[test]
+fn clause_02_feat_for_features() {
+    assert_eq!(parse("feat: add login").header.commit_type, "feat");
+}
+
+#[test]
+fn clause_03_fix_for_bug_fixes() {
+    assert_eq!(parse("fix: handle empty input").header.commit_type, "fix");
+}
+
+#[test]
+fn clause_04_optional_scope_in_parentheses() {
+    assert_eq!(
+        parse("fix(parser): handle empty input").header.scope,
+        Some(vec!["parser".to_string()])
+    );
+    assert_eq!(parse("fix: handle empty input").header.scope, None);
+
+    assert_eq!(codes("fix(): handle empty input"), ["CP0008"]);
+    assert!(codes("fix(parser: handle empty input").contains(&"CP0004".to_string()));
+}
+
+#[test]
+fn clause_05_description_follows_colon_and_space() {
+    assert_eq!(
+        parse("fix: handle empty input").header.description,
+        "handle empty input"
+    );
+
+    assert_eq!(codes("fix: "), ["CP0006"]);
+    assert_eq!(codes("fix:   handle empty input"), ["CP0012"]);
+}
+
+#[test]
+fn clause_06_body_after_one_blank_line() {
+    let message = parse("fix: handle empty input\n\nEmpty files used to panic.");
+    assert_eq!(message.body.as_deref(), Some("Empty files used to panic."));
+
+    assert_eq!(
+        codes("fix: handle empty input\nEmpty files used to panic."),
+        ["CP0018"]
+    );
+}
+
+#[test]
+fn clause_07_free_form_body_paragraphs() {
+    let message = parse("fix: handle empty input\n\nFirst paragraph.\n\nSecond\nparagraph.");
+    assert_eq!(
+        message.body.as_deref(),
+        Some("First paragraph.\n\nSecond\nparagraph.")
+    );
+}
+
+#[test]
+fn clause_08_footers_with_colon_or_hash_separator() {
+    let message = parse("fix: handle empty input\n\nReviewed-by: Z\nRefs #133");
+    assert_eq!(message.body, None);
+    let trailers: Vec<_> = message
+        .trailers
+        .iter()
+        .map(|trailer| {
+            (
+                trailer.token.as_str(),
+                trailer.separator,
+                trailer.value.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(trailers, [("Reviewed-by", ':', "Z"), ("Refs", '#', "133")]);
+}
+
+#[test]
+fn clause_09_footer_tokens_use_dashes() {
+    assert_eq!(
+        parse("fix: x y\n\nAcked-by: Z").trailers[0].token,
+        "Acked-by"
+    );
+    assert_eq!(
+        codes("fix: x y\n\nSigned-off-by: A\nAcked by: Z"),
+        ["CP0020"]
+    );
+    assert_eq!(
+        parse("fix: x y\n\nBREAKING CHANGE: drop v1").trailers[0].token,
+        "BREAKING CHANGE"
+    );
+}
+
+#[test]
+fn clause_10_footer_values_span_lines_until_the_next_token() {
+    let message = parse("fix: x y\n\nBREAKING CHANGE: drop v1\n  and v2 clients\nRefs: #12");
+    assert_eq!(message.trailers[0].value, "drop v1\nand v2 clients");
+    assert_eq!(message.trailers[1].token, "Refs");
+    assert_eq!(message.trailers[1].value, "#12");
+}
+
+#[test]
+fn clause_11_breaking_changes_in_prefix_or_footer() {
+    assert!(parse("feat!: drop v1").header.breaking);
+    assert!(
+        parse("feat: drop v1\n\nBREAKING CHANGE: v1 is gone")
+            .header
+            .breaking
+    );
+    assert!(!parse("feat: add v2").header.breaking);
+}
+
+#[test]
+fn clause_12_breaking_change_footer_is_uppercase() {
+    assert!(
+        parse("feat: drop v1\n\nBREAKING CHANGE: v1 is gone")
+            .header
+            .breaking
+    );
+    assert!(
+        !parse("feat: drop v1\n\nbreaking change: v1 is gone")
+            .header
+            .breaking
+    );
+}
+
+#[test]
+fn clause_13_exclamation_mark_before_the_colon() {
+    let message = parse("feat(api)!: drop v1");
+    assert!(message.header.breaking);
+    assert!(message.trailers.is_empty());
+
+    assert!(!codes("feat!(api): drop v1").is_empty());
+}
+
+#[test]
+fn clause_14_other_types_may_be_used() {
+    assert_eq!(parse("docs: fix typo").header.commit_type, "docs");
+    assert_eq!(
+        parse("refactor(parser): split module").header.commit_type,
+        "refactor"
+    );
+}
+
+#[test]
+fn clause_15_units_are_case_insensitive() {
+    assert_eq!(parse("FEAT: add login").header.commit_type, "feat");
+    assert_eq!(
+        parse("Fix(api): handle empty input").header.commit_type,
+        "fix"
+    );
+    assert_eq!(
+        parse("fix: x y\n\nreviewed-by: Z").trailers[0].token,
+        "reviewed-by"
+    );
+}
+
+#[test]
+fn clause_16_breaking_dash_change_is_a_synonym() {
+    assert!(
+        parse("feat: drop v1\n\nBREAKING-CHANGE: v1 is gone")
+            .header
+            .breaking
+    );
+}
+
+#[test]
+fn house_style_checks_are_off() {
+    let linter = Linter::from_config(&Config {
+        mode: ParserMode::SpecStrict,
+        scopes: Some(vec!["api".into()]),
+        ..Config::default()
+    });
+    assert!(linter.lint("Feat( API ): add login  ").is_ok());
+
+    let house_style = Linter::from_config(&Config::default());
+    assert!(house_style.lint("Feat: add login").is_err());
+}
+
+#[test]
+fn configured_rules_stay_active() {
+    let linter = Linter::from_config(&Config {
+        mode: ParserMode::SpecStrict,
+        description: DescriptionConfig {
+            case: DescriptionCase::LowerFirst,
+            no_trailing_period: true,
+            ..DescriptionConfig::default()
+        },
+        header_max_length: Some(20),
+        ..Config::default()
+    });
+    assert_eq!(
+        codes_with(&linter, "feat: Add a really long description here"),
+        ["CP0021", "CP0025"]
+    );
+    assert_eq!(codes_with(&linter, "feat: add login."), ["CP0022"]);
+}
@@ -92,6 +92,29 @@ fn test_end_to_end_edge_cases() {
     assert!(parser.parse(&format!("feat: {}", long_desc)).is_ok());
 }
 
+#[test]
+fn test_end_to_end_full_message() {
+    let config = Config::default();
+    let parser = ConventionalParser::new(config.types, config.scopes);
+
+    let input =
+        "feat(api): add endpoint\n\nExplain the change.\n\nBREAKING CHANGE: drops v1\nRefs: #42";
+    let result = parser.parse_message(input);
+    assert!(result.is_ok());
+    if let Some(message) = result.output() {
+        assert!(message.header.breaking);
+        assert_eq!(message.body.as_deref(), Some("Explain the change."));
+        assert_eq!(message.trailers.len(), 2);
+    }
+
+    let input = "feat(api): add endpoint\nExplain the change.";
+    let result = parser.parse_message(input);
+    let report = result
+        .report(OutputFormat::Ascii, CharSetConfig::Ascii)
+        .expect("Expected parse to fail");
+    assert!(report.contains("Missing blank line after header"));
+}
+
 #[test]
 fn test_config_with_custom_types() {
     let config = Config {
@@ -228,3 +251,20 @@ fn test_config_with_all_formats() {
         assert!(result.is_ok(), "Failed to load {}", format_path);
     }
 }
+
+#[test]
+fn test_rules_from_config_turn_errors_into_warnings() {
+    let config = Config::parse_str(
+        "rules:\n  trailing-spaces: warn\n",
+        conventional_prs::ConfigFormat::Yaml,
+    )
+    .unwrap();
+    let parser = ConventionalParser::from_config(&config);
+
+    let result = parser.parse("feat: add api  ");
+    assert!(result.is_ok());
+    let report = result
+        .report(OutputFormat::Ascii, CharSetConfig::Ascii)
+        .unwrap();
+    assert!(report.contains("[CP0015] Warning: Trailing spaces"));
+}
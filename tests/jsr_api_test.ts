@@ -134,7 +134,7 @@ Deno.test("parse() throws full pretty report by default", () => {
     message = error instanceof Error ? error.message : String(error)
   }
 
-  assertEquals(message.includes("Error: Invalid commit type"), true)
+  assertEquals(message.includes("[CP0001] Error: Invalid commit type"), true)
 })
 
 Deno.test("parseConfig() parses semantic.yml text and returns a usable schema", () => {